@@ -1,8 +1,11 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{Data, DeriveInput, Expr, Ident, LitBool, LitInt, spanned::Spanned};
+use syn::{
+    Data, DeriveInput, Expr, Ident, LitBool, LitInt, meta::ParseNestedMeta, parenthesized,
+    spanned::Spanned,
+};
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum Side {
     North,
     East,
@@ -10,6 +13,38 @@ enum Side {
     West,
 }
 
+impl Side {
+    fn to_tokens(self) -> proc_macro2::TokenStream {
+        match self {
+            Side::North => quote! {crate::content::machine_types::Side::North},
+            Side::East => quote! {crate::content::machine_types::Side::East},
+            Side::South => quote! {crate::content::machine_types::Side::South},
+            Side::West => quote! {crate::content::machine_types::Side::West},
+        }
+    }
+}
+
+/// An input or output on one of the edge tiles of a machine
+///
+/// `index` counts the tiles along the edge, from west to east or from south to north.
+#[derive(Debug)]
+struct Port {
+    side: Side,
+    index: u32,
+}
+
+impl Port {
+    /// The offset of the port's tile from the machine's south west tile
+    fn offset(&self, size: u32) -> (u32, u32) {
+        match self.side {
+            Side::North => (self.index, size - 1),
+            Side::East => (size - 1, self.index),
+            Side::South => (self.index, 0),
+            Side::West => (0, self.index),
+        }
+    }
+}
+
 #[derive(Debug)]
 enum TunnelType {
     Input,
@@ -19,34 +54,80 @@ enum TunnelType {
 #[derive(Debug)]
 struct Variant {
     name: Ident,
-    inputs: Option<Vec<Side>>,
-    outputs: Option<Vec<Side>>,
+    inputs: Option<Vec<Port>>,
+    outputs: Option<Vec<Port>>,
     texture: LitInt,
+    size: u32,
     should_render: bool,
     machine: Expr,
     tunnel_type: Option<TunnelType>,
+    tunnel_range: Option<LitInt>,
 }
 
 impl Variant {
     fn new(
         name: Ident,
-        inputs: Option<Vec<Side>>,
-        outputs: Option<Vec<Side>>,
+        inputs: Option<Vec<Port>>,
+        outputs: Option<Vec<Port>>,
         texture: LitInt,
+        size: u32,
         should_render: bool,
         machine: Expr,
         tunnel_type: Option<TunnelType>,
+        tunnel_range: Option<LitInt>,
     ) -> Self {
         Self {
             name,
             inputs,
             outputs,
             texture,
+            size,
             should_render,
             machine,
             tunnel_type,
+            tunnel_range,
+        }
+    }
+}
+
+/// Parse a port like `North` or `North(1)`
+fn parse_port(nested_meta: &ParseNestedMeta) -> syn::Result<Port> {
+    let side = if nested_meta.path.is_ident("North") {
+        Side::North
+    } else if nested_meta.path.is_ident("East") {
+        Side::East
+    } else if nested_meta.path.is_ident("South") {
+        Side::South
+    } else if nested_meta.path.is_ident("West") {
+        Side::West
+    } else {
+        return Err(nested_meta.error("unsupported side"));
+    };
+
+    // The index along the edge is optional and defaults to the first tile
+    let index = if nested_meta.input.peek(syn::token::Paren) {
+        let content;
+        parenthesized!(content in nested_meta.input);
+        let index_value: LitInt = content.parse()?;
+        index_value.base10_parse()?
+    } else {
+        0
+    };
+
+    Ok(Port { side, index })
+}
+
+/// Get all the different sides of `ports` in the order they first appear in
+fn unique_sides(ports: &[Port]) -> Vec<Side> {
+    let mut sides = Vec::new();
+
+    for port in ports {
+        if !sides.contains(&port.side) {
+            sides.push(port.side);
         }
     }
+
+    sides
 }
 
 pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
@@ -84,9 +165,11 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
                 let mut inputs = Vec::new();
                 let mut outputs = Vec::new();
                 let mut texture_index = None;
+                let mut size = 1;
                 let mut machine: Option<Expr> = None;
                 let mut should_render = false;
                 let mut tunnel_type = None;
+                let mut tunnel_range = None;
 
                 let parsing_result = attr.parse_nested_meta(|meta| {
                     if meta.path.is_ident("texture") {
@@ -94,6 +177,16 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
                         let texure_value: LitInt = value.parse()?;
                         texture_index = Some(texure_value);
 
+                        Ok(())
+                    } else if meta.path.is_ident("size") {
+                        let value = meta.value()?;
+                        let size_value: LitInt = value.parse()?;
+                        size = size_value.base10_parse()?;
+
+                        if size == 0 {
+                            return Err(meta.error("the size has to be at least 1"));
+                        }
+
                         Ok(())
                     } else if meta.path.is_ident("render") {
                         let value = meta.value()?;
@@ -116,43 +209,23 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
                             }
                             _ => Err(meta.error("unsupported tunnel type")),
                         }
+                    } else if meta.path.is_ident("range") {
+                        let value = meta.value()?;
+                        let range_value: LitInt = value.parse()?;
+                        tunnel_range = Some(range_value);
+
+                        Ok(())
                     } else if meta.path.is_ident("inputs") {
                         meta.parse_nested_meta(|nested_meta| {
-                            if nested_meta.path.is_ident("North") {
-                                inputs.push(Side::North);
-                                Ok(())
-                            } else if nested_meta.path.is_ident("East") {
-                                inputs.push(Side::East);
-                                Ok(())
-                            } else if nested_meta.path.is_ident("South") {
-                                inputs.push(Side::South);
-                                Ok(())
-                            } else if nested_meta.path.is_ident("West") {
-                                inputs.push(Side::West);
-                                Ok(())
-                            } else {
-                                Err(meta.error("unsupported side"))
-                            }
+                            inputs.push(parse_port(&nested_meta)?);
+                            Ok(())
                         })?;
 
                         Ok(())
                     } else if meta.path.is_ident("outputs") {
                         meta.parse_nested_meta(|nested_meta| {
-                            if nested_meta.path.is_ident("North") {
-                                outputs.push(Side::North);
-                                Ok(())
-                            } else if nested_meta.path.is_ident("East") {
-                                outputs.push(Side::East);
-                                Ok(())
-                            } else if nested_meta.path.is_ident("South") {
-                                outputs.push(Side::South);
-                                Ok(())
-                            } else if nested_meta.path.is_ident("West") {
-                                outputs.push(Side::West);
-                                Ok(())
-                            } else {
-                                Err(meta.error("unsupported side"))
-                            }
+                            outputs.push(parse_port(&nested_meta)?);
+                            Ok(())
                         })?;
 
                         Ok(())
@@ -183,6 +256,12 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
                         .into();
                 };
 
+                if inputs.iter().chain(&outputs).any(|port| port.index >= size) {
+                    return syn::Error::new(attr.span(), "port index is outside of the machine")
+                        .into_compile_error()
+                        .into();
+                }
+
                 variants.push(Variant::new(
                     name.clone(),
                     if inputs.is_empty() {
@@ -196,9 +275,11 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
                         Some(outputs)
                     },
                     texture_index,
+                    size,
                     should_render,
                     machine.clone(),
                     tunnel_type,
+                    tunnel_range,
                 ));
             }
             None => {
@@ -216,17 +297,8 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
             let name = &variant.name;
 
             match variant.inputs.as_ref() {
-                Some(input_sides) => {
-                    let mut sides = Vec::new();
-
-                    for side in input_sides {
-                        sides.push(match side {
-                            Side::North => quote! {crate::content::machine_types::Side::North},
-                            Side::East => quote! {crate::content::machine_types::Side::East},
-                            Side::South => quote! {crate::content::machine_types::Side::South},
-                            Side::West => quote! {crate::content::machine_types::Side::West},
-                        });
-                    }
+                Some(input_ports) => {
+                    let sides = unique_sides(input_ports).into_iter().map(Side::to_tokens);
 
                     matches.push(quote! {
                         Self::#name => Some(vec![#(#sides),*]),
@@ -254,17 +326,8 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
             let name = &variant.name;
 
             match variant.outputs.as_ref() {
-                Some(output_sides) => {
-                    let mut sides = Vec::new();
-
-                    for side in output_sides {
-                        sides.push(match side {
-                            Side::North => quote! {crate::content::machine_types::Side::North},
-                            Side::East => quote! {crate::content::machine_types::Side::East},
-                            Side::South => quote! {crate::content::machine_types::Side::South},
-                            Side::West => quote! {crate::content::machine_types::Side::West},
-                        });
-                    }
+                Some(output_ports) => {
+                    let sides = unique_sides(output_ports).into_iter().map(Side::to_tokens);
 
                     matches.push(quote! {
                         Self::#name => Some(vec![#(#sides),*]),
@@ -285,6 +348,28 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
         }
     };
 
+    let get_input_ports = ports_fn_body(&variants, |variant| variant.inputs.as_ref());
+    let get_output_ports = ports_fn_body(&variants, |variant| variant.outputs.as_ref());
+
+    let size = {
+        let mut matches = Vec::new();
+
+        for variant in &variants {
+            let size = variant.size;
+            let variant_name = &variant.name;
+
+            matches.push(quote! {
+                Self::#variant_name => #size,
+            });
+        }
+
+        quote! {
+            match self {
+                #(#matches)*
+            }
+        }
+    };
+
     let should_render = {
         let mut matches = Vec::new();
 
@@ -334,6 +419,29 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
         }
     };
 
+    let tunnel_range = {
+        let mut matches = Vec::new();
+
+        for variant in &variants {
+            let tunnel_range = match &variant.tunnel_range {
+                Some(tunnel_range) => quote! {Some(#tunnel_range)},
+                None => quote! {None},
+            };
+
+            let variant_name = &variant.name;
+
+            matches.push(quote! {
+                Self::#variant_name => #tunnel_range,
+            });
+        }
+
+        quote! {
+            match self {
+                #(#matches)*
+            }
+        }
+    };
+
     let machine_variant_to_texture_index = {
         let mut matches = Vec::new();
 
@@ -367,8 +475,15 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
             let variant_name = &variant.name;
 
             if &variant_name.to_string() != "Nothing" {
+                // Every tile of a bigger machine has its own texture right after the first one
+                let first_texture_index: u32 = match texture_index.base10_parse() {
+                    Ok(first_texture_index) => first_texture_index,
+                    Err(err) => return err.to_compile_error().into(),
+                };
+                let last_texture_index = first_texture_index + variant.size * variant.size - 1;
+
                 matches.push(quote! {
-                    #texture_index => #name::#variant_name,
+                    #first_texture_index..=#last_texture_index => #name::#variant_name,
                 });
             }
         }
@@ -466,6 +581,32 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
                 #get_output_sides
             }
 
+            /// Get the input ports as the offset of their tile from the south west tile and their side
+            pub fn get_input_ports(&self) -> Vec<(bevy::math::UVec2, Side)> {
+                #get_input_ports
+            }
+
+            /// Get the output ports as the offset of their tile from the south west tile and their side
+            pub fn get_output_ports(&self) -> Vec<(bevy::math::UVec2, Side)> {
+                #get_output_ports
+            }
+
+            /// The width and height of the machine in tiles
+            pub fn size(&self) -> u32 {
+                #size
+            }
+
+            /// Get the texture of the tile at `offset` from the south west tile
+            pub fn texture_index_at(&self, offset: bevy::math::UVec2) -> bevy_ecs_tilemap::tiles::TileTextureIndex {
+                let bevy_ecs_tilemap::tiles::TileTextureIndex(first_texture_index) = (*self)
+                    .try_into()
+                    .expect("Only placeable machines have a texture");
+
+                bevy_ecs_tilemap::tiles::TileTextureIndex(
+                    first_texture_index + offset.y * self.size() + offset.x,
+                )
+            }
+
             pub fn should_render_item(&self) -> bool {
                 #should_render
             }
@@ -474,6 +615,10 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
                 #tunnel_type
             }
 
+            pub fn tunnel_range(&self) -> Option<u8> {
+                #tunnel_range
+            }
+
             pub fn select_next(&mut self) {
                 #select_next
             }
@@ -507,3 +652,32 @@ pub fn impl_foreground_objects_macro(ast: &DeriveInput) -> TokenStream {
     }
     .into()
 }
+
+/// Generate the body of a function returning the ports chosen by `get_ports` for every variant
+fn ports_fn_body(
+    variants: &[Variant],
+    get_ports: impl Fn(&Variant) -> Option<&Vec<Port>>,
+) -> proc_macro2::TokenStream {
+    let mut matches = Vec::new();
+
+    for variant in variants {
+        let name = &variant.name;
+
+        let ports = get_ports(variant).into_iter().flatten().map(|port| {
+            let (x, y) = port.offset(variant.size);
+            let side = port.side.to_tokens();
+
+            quote! {(bevy::math::UVec2::new(#x, #y), #side)}
+        });
+
+        matches.push(quote! {
+            Self::#name => vec![#(#ports),*],
+        });
+    }
+
+    quote! {
+        match self {
+            #(#matches)*
+        }
+    }
+}
@@ -1,13 +1,13 @@
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
 use serde::{Deserialize, Serialize};
-use strum_macros::Display;
+use strum_macros::{Display, EnumIter};
 
 // TODO: Fix items skipping one in loops
 // - add flag to item if it has already moved
 // - check before moving
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize, Display)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, Serialize, Deserialize, Display, EnumIter)]
 pub enum ItemType {
     #[strum(to_string = "Coal")]
     Coal,
@@ -51,12 +51,65 @@ pub enum ItemType {
     Engine,
     #[strum(to_string = "Helicopter")]
     Helicopter,
+    #[strum(to_string = "Speed Module")]
+    SpeedModule,
+    #[strum(to_string = "Efficiency Module")]
+    EfficiencyModule,
+    #[strum(to_string = "Productivity Module")]
+    ProductivityModule,
+    #[strum(to_string = "Quartz Sand")]
+    QuartzSand,
+    #[strum(to_string = "Stone")]
+    Stone,
+    #[strum(to_string = "Crude Oil")]
+    CrudeOil,
+    #[strum(to_string = "Glass")]
+    Glass,
+    #[strum(to_string = "Silicon")]
+    Silicon,
+    #[strum(to_string = "Brick")]
+    Brick,
+    #[strum(to_string = "Plastic")]
+    Plastic,
 }
 
 impl ItemType {
     pub fn ends_game(&self) -> bool {
         *self == Self::Helicopter
     }
+
+    /// How much burn time this item provides when it's used as fuel
+    ///
+    /// Returns `None` if the item can't be burned.
+    pub fn fuel_value(&self) -> Option<u16> {
+        match self {
+            Self::Coal => Some(60),
+            Self::Battery => Some(400),
+            _ => None,
+        }
+    }
+
+    /// How many points destroying this item in a sink is worth
+    pub fn points(&self) -> u32 {
+        match self {
+            Self::Coal
+            | Self::RawCopper
+            | Self::RawIron
+            | Self::QuartzSand
+            | Self::Stone
+            | Self::CrudeOil => 1,
+            Self::CopperIngot | Self::IronIngot | Self::Glass | Self::Brick => 2,
+            Self::Silicon | Self::Plastic => 5,
+            Self::Gear | Self::Wire => 3,
+            Self::Steel => 5,
+            Self::ReinforcedSteel | Self::ElectricalCircuit | Self::RotorBlade => 10,
+            Self::MicroProcessor | Self::Propeller | Self::Battery => 25,
+            Self::BigPropeller | Self::Hull | Self::Motor | Self::ControlModule => 50,
+            Self::SpeedModule | Self::EfficiencyModule | Self::ProductivityModule => 75,
+            Self::HelicopterFrame | Self::Engine => 100,
+            Self::Helicopter => 1000,
+        }
+    }
 }
 
 impl From<ItemType> for TileTextureIndex {
@@ -83,6 +136,16 @@ impl From<ItemType> for TileTextureIndex {
             ItemType::HelicopterFrame => 18,
             ItemType::Engine => 19,
             ItemType::Helicopter => 20,
+            ItemType::SpeedModule => 21,
+            ItemType::EfficiencyModule => 22,
+            ItemType::ProductivityModule => 23,
+            ItemType::QuartzSand => 24,
+            ItemType::Stone => 25,
+            ItemType::CrudeOil => 26,
+            ItemType::Glass => 27,
+            ItemType::Silicon => 28,
+            ItemType::Brick => 29,
+            ItemType::Plastic => 30,
         })
     }
 }
@@ -1,14 +1,22 @@
 use as_any::AsAny;
 use bevy::prelude::*;
-use dyn_clone::DynClone;
+use dyn_clone::{DynClone, clone_box};
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
 
-use std::{collections::VecDeque, fmt::Debug};
+use std::{
+    collections::{HashMap, VecDeque},
+    fmt::Debug,
+};
 
 use crate::{
     Direction,
-    content::items::{Item, ItemType},
+    content::{
+        items::{Item, ItemType},
+        modules::ModuleSlots,
+        signals::SignalCondition,
+    },
     plugins::world::MiddlegroundObject,
 };
 
@@ -18,6 +26,22 @@ pub struct Machine {
     pub machine_type: Box<dyn MachineType>,
     pub input_items: InputItems,
     pub output_items: Option<OutputItems>,
+    #[serde(default)]
+    pub power: MachinePower,
+
+    /// Whether this machine comes from a save from before power existed
+    ///
+    /// Such machines run at full speed until they are connected to a power network for the first time.
+    #[serde(default)]
+    pub legacy_power: bool,
+
+    /// Whether the signal condition of this machine currently stops it, updated every simulation tick
+    #[serde(skip)]
+    pub disabled_by_signal: bool,
+
+    /// Saved together with the machine, machines of older saves start without statistics
+    #[serde(default)]
+    pub statistics: MachineStatistics,
 }
 
 impl Machine {
@@ -30,16 +54,91 @@ impl Machine {
             machine_type,
             input_items,
             output_items,
+            power: MachinePower::default(),
+            legacy_power: false,
+            disabled_by_signal: false,
+            statistics: MachineStatistics::default(),
         }
     }
 
     pub fn perform_action(&mut self, middleground_object: Option<MiddlegroundObject>) {
+        if self.disabled_by_signal {
+            return;
+        }
+
+        match self.machine_type.power_role() {
+            PowerRole::Consumer(_) => {
+                // A machine without enough power only acts on some ticks
+                self.power.progress += self.power.satisfaction;
+
+                if self.power.progress < 1.0 {
+                    return;
+                }
+
+                self.power.progress -= 1.0;
+            }
+            PowerRole::Producer(_) => self.machine_type.set_power_load(self.power.load),
+            PowerRole::None | PowerRole::Pole(_) => {}
+        }
+
         self.machine_type.perform_action(
             &mut self.input_items,
             self.output_items.as_mut(),
             middleground_object,
         );
     }
+
+    /// Whether this machine is making something and isn't stopped by a signal or a lack of power
+    pub fn is_working(&self) -> bool {
+        if self.disabled_by_signal || self.machine_type.progress().is_none() {
+            return false;
+        }
+
+        match self.machine_type.power_role() {
+            PowerRole::Consumer(_) => self.power.satisfaction > 0.0,
+            PowerRole::None | PowerRole::Producer(_) | PowerRole::Pole(_) => true,
+        }
+    }
+}
+
+impl Clone for Machine {
+    fn clone(&self) -> Self {
+        Self {
+            machine_type: clone_box(&*self.machine_type),
+            input_items: self.input_items.clone(),
+            output_items: self.output_items.clone(),
+            power: self.power,
+            legacy_power: self.legacy_power,
+            disabled_by_signal: self.disabled_by_signal,
+            statistics: self.statistics.clone(),
+        }
+    }
+}
+
+/// The power state of a machine, updated by its power network every simulation tick
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
+pub struct MachinePower {
+    /// The share of the needed power this machine receives, from 0 to 1
+    pub satisfaction: f32,
+
+    /// The share of this machine's power production that is used, from 0 to 1
+    pub load: f32,
+
+    /// Progress towards the next action of a machine that isn't fully powered
+    progress: f32,
+}
+
+/// What a machine did since it was placed, shown in the machine inspector
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct MachineStatistics {
+    /// How many items of every type this machine made
+    pub produced_items: HashMap<ItemType, u64>,
+
+    /// How many simulation ticks this machine existed
+    pub ticks: u64,
+
+    /// How many of those ticks this machine was working, see [Machine::is_working]
+    pub working_ticks: u64,
 }
 
 // MARK: MachineType
@@ -130,6 +229,176 @@ pub trait MachineType: Debug + Send + Sync + AsAny + DynClone {
     fn tick_after_first(&self) -> bool {
         false
     }
+
+    /// How this machine takes part in a power network
+    fn power_role(&self) -> PowerRole {
+        PowerRole::None
+    }
+
+    /// Called before every action of a power producer with the share of its production that is used
+    fn set_power_load(&mut self, _load: f32) {}
+
+    /// Whether this machine connects to its neighbors regardless of their input and output sides
+    ///
+    /// This is used by inserters, which grab items from and insert them into any machine.
+    fn connects_to_any_side(&self) -> bool {
+        false
+    }
+
+    /// The settings shown in the settings menu when clicking on this machine
+    ///
+    /// Machines returning settings here also have to be selectable.
+    fn settings(&self) -> Vec<MachineSetting> {
+        Vec::new()
+    }
+
+    /// Select the option at `option` for the setting at `setting`
+    ///
+    /// The indices are the same as in [MachineType::settings].
+    fn apply_setting(&mut self, _setting: usize, _option: usize) {}
+
+    /// The slots shown in the inventory window of this machine
+    ///
+    /// `None` if this machine doesn't store items.
+    fn inventory(&self) -> Option<&[ItemSlot]> {
+        None
+    }
+
+    /// The module slots shown in the recipe menu of this machine
+    ///
+    /// `None` if no modules can be inserted into this machine.
+    fn modules(&self) -> Option<&ModuleSlots> {
+        None
+    }
+
+    /// Mutable access to the module slots of this machine, see [MachineType::modules]
+    fn modules_mut(&mut self) -> Option<&mut ModuleSlots> {
+        None
+    }
+
+    /// The condition the signals around this machine have to meet for it to run
+    ///
+    /// `None` if this machine can't be controlled by signals.
+    fn signal_condition(&self) -> Option<&SignalCondition> {
+        None
+    }
+
+    /// The items this machine made since the last call, counted for the statistics of the run
+    fn take_produced_items(&mut self) -> Vec<(ItemType, u32)> {
+        Vec::new()
+    }
+
+    /// How far this machine is with the item it's currently making, from 0 to 1
+    ///
+    /// `None` if it isn't making anything right now.
+    fn progress(&self) -> Option<f32> {
+        None
+    }
+}
+
+// MARK: ItemSlot
+
+/// A slot storing up to [ItemSlot::CAPACITY] items of a single type
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ItemSlot {
+    pub item: Option<ItemType>,
+    pub count: u16,
+
+    /// Only items of this type can be stored in this slot, `None` allows everything
+    pub filter: Option<ItemType>,
+}
+
+impl ItemSlot {
+    pub const CAPACITY: u16 = 50;
+
+    /// How many items of type `item` still fit into this slot
+    pub fn free_space(&self, item: ItemType) -> u16 {
+        if self.filter.is_some_and(|filter| filter != item) {
+            return 0;
+        }
+
+        match self.item {
+            Some(stored_item) if stored_item == item => Self::CAPACITY - self.count,
+            Some(_) => 0,
+            None => Self::CAPACITY,
+        }
+    }
+
+    /// Store one item of type `item`, there has to be free space for it
+    pub fn insert(&mut self, item: ItemType) {
+        self.item = Some(item);
+        self.count += 1;
+    }
+
+    /// Take one item out of this slot
+    pub fn take(&mut self) -> Option<ItemType> {
+        let item = self.item?;
+
+        self.count -= 1;
+
+        if self.count == 0 {
+            self.item = None;
+        }
+
+        Some(item)
+    }
+}
+
+// MARK: MachineSetting
+
+/// A setting of a machine with all its options
+#[derive(Debug, Clone)]
+pub struct MachineSetting {
+    pub name: String,
+    pub options: Vec<String>,
+    pub selected: usize,
+}
+
+impl MachineSetting {
+    pub fn new(name: impl Into<String>, options: Vec<String>, selected: usize) -> Self {
+        Self {
+            name: name.into(),
+            options,
+            selected,
+        }
+    }
+
+    /// A setting to choose one item type or none at all
+    ///
+    /// Use [MachineSetting::item_filter_option] to get the chosen item type back.
+    pub fn item_filter(name: impl Into<String>, filter: Option<ItemType>) -> Self {
+        let options = std::iter::once(String::from("Any"))
+            .chain(ItemType::iter().map(|item| item.to_string()))
+            .collect();
+
+        let selected = filter
+            .and_then(|filter| ItemType::iter().position(|item| item == filter))
+            .map_or(0, |position| position + 1);
+
+        Self::new(name, options, selected)
+    }
+
+    /// The item type chosen at `option` in a setting created with [MachineSetting::item_filter]
+    pub fn item_filter_option(option: usize) -> Option<ItemType> {
+        option
+            .checked_sub(1)
+            .and_then(|index| ItemType::iter().nth(index))
+    }
+}
+
+// MARK: PowerRole
+
+/// How a machine takes part in a power network
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerRole {
+    /// The machine neither needs nor provides power
+    None,
+    /// The machine needs this much power per tick to run at full speed
+    Consumer(u32),
+    /// The machine currently provides this much power per tick
+    Producer(u32),
+    /// The machine connects all machines up to this many tiles around it to the network
+    Pole(u32),
 }
 
 pub type Side = Direction;
@@ -18,15 +18,66 @@ impl MachineType for Belt {
     fn perform_action(
         &mut self,
         input_items: &mut InputItems,
-        mut output_items: Option<&mut OutputItems>,
+        output_items: Option<&mut OutputItems>,
         _middleground_object: Option<MiddlegroundObject>,
     ) {
-        if output_items.unwrap_single_side().is_empty()
-            && let Some(input_item) = input_items.exactly_one_mut().pop_front()
-        {
-            output_items.unwrap_single_side_mut().push_back(input_item);
+        move_belt_items(input_items, output_items, 1);
+    }
+
+    fn can_accept(
+        &self,
+        _item: &ItemType,
+        input_items: &InputItems,
+        output_items: Option<&OutputItems>,
+        _input_side: &Side,
+    ) -> bool {
+        belt_has_space(input_items, output_items, 1)
+    }
+
+    fn tick_after_first(&self) -> bool {
+        true
+    }
+}
+
+/// The faster belts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BeltTier {
+    Express,
+    Turbo,
+}
+
+impl BeltTier {
+    /// How many items a belt of this tier can hold and move every tick
+    pub fn capacity(&self) -> usize {
+        match self {
+            Self::Express => 2,
+            Self::Turbo => 4,
         }
     }
+}
+
+/// A belt holding and moving multiple items at once
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct TieredBelt {
+    pub tier: BeltTier,
+}
+
+impl TieredBelt {
+    pub fn new(tier: BeltTier) -> Self {
+        Self { tier }
+    }
+}
+
+#[typetag::serde]
+impl MachineType for TieredBelt {
+    fn perform_action(
+        &mut self,
+        input_items: &mut InputItems,
+        output_items: Option<&mut OutputItems>,
+        _middleground_object: Option<MiddlegroundObject>,
+    ) {
+        move_belt_items(input_items, output_items, self.tier.capacity());
+    }
 
     fn can_accept(
         &self,
@@ -35,10 +86,32 @@ impl MachineType for Belt {
         output_items: Option<&OutputItems>,
         _input_side: &Side,
     ) -> bool {
-        (input_items.exactly_one().len() + output_items.unwrap_single_side().len()) == 0
+        belt_has_space(input_items, output_items, self.tier.capacity())
     }
 
     fn tick_after_first(&self) -> bool {
         true
     }
 }
+
+/// Move items from the input to the output of a belt, until the output is full
+fn move_belt_items(
+    input_items: &mut InputItems,
+    mut output_items: Option<&mut OutputItems>,
+    capacity: usize,
+) {
+    while output_items.unwrap_single_side().len() < capacity
+        && let Some(input_item) = input_items.exactly_one_mut().pop_front()
+    {
+        output_items.unwrap_single_side_mut().push_back(input_item);
+    }
+}
+
+/// Whether a belt holding `capacity` items has space for another one
+fn belt_has_space(
+    input_items: &InputItems,
+    output_items: Option<&OutputItems>,
+    capacity: usize,
+) -> bool {
+    input_items.exactly_one().len() + output_items.unwrap_single_side().len() < capacity
+}
@@ -3,21 +3,80 @@ use serde::{Deserialize, Serialize};
 use crate::{
     content::{
         items::ItemType,
-        machine_types::{InputItems, MachineType, OutputItems, Side, UnwrapOutputItemsMut},
+        machine_types::{
+            InputItems, ItemSlot, MachineSetting, MachineType, OutputItems, Side,
+            UnwrapOutputItemsMut,
+        },
     },
     plugins::world::MiddlegroundObject,
 };
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+const ALL_SIDES: [Side; 4] = [Side::North, Side::East, Side::South, Side::West];
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Chest {
     pub last_side: Side,
+
+    #[serde(default = "Chest::default_slots")]
+    slots: Vec<ItemSlot>,
+
+    /// The slot the next item is taken out of
+    #[serde(default)]
+    next_slot_index: usize,
+
+    /// Whether items are outputted on the north, east, south and west side
+    #[serde(default = "Chest::default_output_sides")]
+    pub output_sides: [bool; 4],
 }
 
 impl Chest {
+    const SLOT_COUNT: usize = 8;
+
+    /// How many items can wait in the output to be moved to the machines around
+    const OUTPUT_BUFFER_SIZE: usize = 4;
+
     pub fn new() -> Self {
         Self {
             last_side: Side::North,
+            slots: Self::default_slots(),
+            next_slot_index: 0,
+            output_sides: Self::default_output_sides(),
+        }
+    }
+
+    fn default_slots() -> Vec<ItemSlot> {
+        vec![ItemSlot::default(); Self::SLOT_COUNT]
+    }
+
+    fn default_output_sides() -> [bool; 4] {
+        [true; 4]
+    }
+
+    /// Find the slot an item of type `item` should be stored in
+    ///
+    /// Slots already holding this item are filled up first.
+    fn slot_for(&mut self, item: ItemType) -> Option<&mut ItemSlot> {
+        let index = self
+            .slots
+            .iter()
+            .position(|slot| slot.item == Some(item) && slot.free_space(item) > 0)
+            .or_else(|| self.slots.iter().position(|slot| slot.free_space(item) > 0))?;
+
+        Some(&mut self.slots[index])
+    }
+
+    /// Take one item out of the slots, going through them one after another
+    fn take_next_item(&mut self) -> Option<ItemType> {
+        for offset in 0..self.slots.len() {
+            let index = (self.next_slot_index + offset) % self.slots.len();
+
+            if let Some(item) = self.slots[index].take() {
+                self.next_slot_index = (index + 1) % self.slots.len();
+                return Some(item);
+            }
         }
+
+        None
     }
 }
 
@@ -29,6 +88,20 @@ impl MachineType for Chest {
         mut output_items: Option<&mut OutputItems>,
         _middleground_object: Option<MiddlegroundObject>,
     ) {
+        // Store the received items
+        for side in ALL_SIDES {
+            let side_items = input_items
+                .get_side_mut(&side)
+                .expect("Chest should have all inputs");
+
+            while let Some(item) = side_items.front()
+                && let Some(slot) = self.slot_for(**item)
+            {
+                slot.insert(**item);
+                side_items.pop_front();
+            }
+        }
+
         let sides = match self.last_side {
             Side::North => [Side::North, Side::East, Side::South, Side::West],
             Side::East => [Side::East, Side::South, Side::West, Side::North],
@@ -40,15 +113,25 @@ impl MachineType for Chest {
         output_items.preferred_sides.clear();
 
         for side in sides {
-            if let Some(item) = input_items
-                .get_side_mut(&side)
-                .expect("Chest should have all inputs")
-                .pop_front()
-            {
-                output_items.items.push_back(item);
+            let side_index = ALL_SIDES
+                .iter()
+                .position(|&other_side| other_side == side)
+                .expect("All sides are in `ALL_SIDES`");
+
+            if self.output_sides[side_index] {
+                output_items.preferred_sides.push(side);
             }
+        }
+
+        // Chests without any output side only store items
+        if output_items.preferred_sides.is_empty() {
+            return;
+        }
 
-            output_items.preferred_sides.push(side);
+        while output_items.items.len() < Self::OUTPUT_BUFFER_SIZE
+            && let Some(item) = self.take_next_item()
+        {
+            output_items.items.push_back(item.into());
         }
 
         if !output_items.items.is_empty() {
@@ -63,11 +146,52 @@ impl MachineType for Chest {
 
     fn can_accept(
         &self,
-        _item: &ItemType,
-        _input_items: &InputItems,
+        item: &ItemType,
+        input_items: &InputItems,
         _output_items: Option<&OutputItems>,
         _input_side: &Side,
     ) -> bool {
+        let free_space: usize = self
+            .slots
+            .iter()
+            .map(|slot| slot.free_space(*item) as usize)
+            .sum();
+
+        // Items still waiting to be stored take up space too
+        free_space > input_items.count_item(item)
+    }
+
+    fn is_selectable(&self) -> bool {
         true
     }
+
+    fn settings(&self) -> Vec<MachineSetting> {
+        let on_off = vec![String::from("On"), String::from("Off")];
+
+        let mut settings: Vec<MachineSetting> =
+            ["Output North", "Output East", "Output South", "Output West"]
+                .into_iter()
+                .zip(self.output_sides)
+                .map(|(name, is_output)| {
+                    MachineSetting::new(name, on_off.clone(), if is_output { 0 } else { 1 })
+                })
+                .collect();
+
+        settings.extend(self.slots.iter().enumerate().map(|(index, slot)| {
+            MachineSetting::item_filter(format!("Slot {} Filter", index + 1), slot.filter)
+        }));
+
+        settings
+    }
+
+    fn apply_setting(&mut self, setting: usize, option: usize) {
+        match setting {
+            0..4 => self.output_sides[setting] = option == 0,
+            _ => self.slots[setting - 4].filter = MachineSetting::item_filter_option(option),
+        }
+    }
+
+    fn inventory(&self) -> Option<&[ItemSlot]> {
+        Some(&self.slots)
+    }
 }
@@ -4,7 +4,8 @@ use crate::{
     content::{
         items::ItemType,
         machine_types::{
-            InputItems, MachineType, OutputItems, Side, UnwrapOutputItems, UnwrapOutputItemsMut,
+            InputItems, MachineSetting, MachineType, OutputItems, Side, UnwrapOutputItems,
+            UnwrapOutputItemsMut,
         },
     },
     plugins::world::MiddlegroundObject,
@@ -14,6 +15,10 @@ use crate::{
 pub struct Combiner {
     input_sides: [Side; 2],
     last_input_side_index: usize,
+
+    /// The index of the input side that is always emptied first, `None` alternates between both
+    #[serde(default)]
+    pub priority_side_index: Option<usize>,
 }
 
 impl Combiner {
@@ -21,6 +26,7 @@ impl Combiner {
         Self {
             input_sides,
             last_input_side_index: 0,
+            priority_side_index: None,
         }
     }
 }
@@ -33,6 +39,29 @@ impl MachineType for Combiner {
         mut output_items: Option<&mut OutputItems>,
         _middleground_object: Option<MiddlegroundObject>,
     ) {
+        if let Some(priority_side_index) = self.priority_side_index {
+            if !output_items.unwrap_single_side().is_empty() {
+                return;
+            }
+
+            // Take from the priority side first and only then from the other one
+            for input_side in [
+                self.input_sides[priority_side_index],
+                self.input_sides[1 - priority_side_index],
+            ] {
+                if let Some(input_item) = input_items
+                    .get_side_mut(&input_side)
+                    .unwrap_or_else(|| panic!("Combiner should have an input at {input_side:?}"))
+                    .pop_front()
+                {
+                    output_items.unwrap_single_side_mut().push_back(input_item);
+                    break;
+                }
+            }
+
+            return;
+        }
+
         // Get current input side
         let current_input_side_index = match self.last_input_side_index {
             0 => 1,
@@ -65,6 +94,14 @@ impl MachineType for Combiner {
         output_items: Option<&OutputItems>,
         input_side: &Side,
     ) -> bool {
+        // With a priority, both sides can hold one item, so the priority side can be chosen
+        if self.priority_side_index.is_some() {
+            return input_items
+                .get_side(input_side)
+                .expect("This side should exist")
+                .is_empty();
+        }
+
         // Only one item is allowed in the combiner
         if input_items.count()
             + output_items
@@ -84,7 +121,28 @@ impl MachineType for Combiner {
         input_side == &self.input_sides[input_side_index]
     }
 
+    fn is_selectable(&self) -> bool {
+        true
+    }
+
     fn tick_after_first(&self) -> bool {
         true
     }
+
+    fn settings(&self) -> Vec<MachineSetting> {
+        vec![MachineSetting::new(
+            "Input Priority",
+            std::iter::once(String::from("None"))
+                .chain(self.input_sides.iter().map(|side| format!("{side:?}")))
+                .collect(),
+            self.priority_side_index.map_or(0, |index| index + 1),
+        )]
+    }
+
+    fn apply_setting(&mut self, setting: usize, option: usize) {
+        match setting {
+            0 => self.priority_side_index = option.checked_sub(1),
+            _ => unreachable!(),
+        }
+    }
 }
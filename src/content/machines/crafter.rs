@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use serde::{Deserialize, Serialize};
 
@@ -6,8 +6,10 @@ use crate::{
     content::{
         items::ItemType,
         machine_types::{
-            InputItems, MachineType, OutputItems, Side, UnwrapOutputItems, UnwrapOutputItemsMut,
+            InputItems, MachineType, OutputItems, PowerRole, Side, UnwrapOutputItems,
+            UnwrapOutputItemsMut,
         },
+        modules::{ModuleSlots, ModuleType},
     },
     plugins::{crafting::recipe_types::CrafterRecipe, world::MiddlegroundObject},
 };
@@ -16,16 +18,88 @@ use crate::{
 pub struct Crafter {
     pub current_recipe: Option<CrafterRecipe>,
 
+    /// Whether the recipe is chosen automatically from the received items
+    ///
+    /// The recipe is locked in once it was chosen.
+    #[serde(default)]
+    pub auto_recipe: bool,
+
     /// Crafting time left
     /// `None` if nothing is currently being crafting
     crafting_time_left: Option<(u16, (ItemType, u8))>,
+
+    #[serde(default)]
+    modules: ModuleSlots,
+
+    /// Items finished since they were last counted for the statistics
+    #[serde(skip)]
+    produced_items: Vec<(ItemType, u32)>,
 }
 
 impl Crafter {
+    pub const POWER_CONSUMPTION: u32 = 10;
+
     pub fn new() -> Self {
         Self {
             current_recipe: None,
+            auto_recipe: true,
             crafting_time_left: None,
+            modules: ModuleSlots::default(),
+            produced_items: Vec::new(),
+        }
+    }
+
+    /// Try to choose the recipe matching the items this crafter received
+    ///
+    /// Does nothing if auto mode is off or a recipe is already set.
+    /// A recipe is only chosen if it's the only one using all the received items
+    /// or if its ingredients are exactly the received items and it can already be crafted.
+    pub fn try_auto_select_recipe(&mut self, input_items: &InputItems, recipes: &[CrafterRecipe]) {
+        if !self.auto_recipe || self.current_recipe.is_some() {
+            return;
+        }
+
+        let mut items = HashMap::new();
+
+        // Modules aren't ingredients of any recipe
+        for item in input_items
+            .all()
+            .into_iter()
+            .filter(|item| ModuleType::from_item(***item).is_none())
+        {
+            items
+                .entry(**item)
+                .and_modify(|count| *count += 1)
+                .or_insert(1);
+        }
+
+        if items.is_empty() {
+            return;
+        }
+
+        let received_items: HashSet<ItemType> = items.keys().copied().collect();
+
+        // All the recipes that use every received item
+        let candidates: Vec<&CrafterRecipe> = recipes
+            .iter()
+            .filter(|recipe| {
+                received_items
+                    .iter()
+                    .all(|item| recipe.ingredients.contains_key(item))
+            })
+            .collect();
+
+        let chosen_recipe = if candidates.len() == 1 {
+            candidates.first().copied()
+        } else {
+            candidates.into_iter().find(|recipe| {
+                recipe.ingredients.len() == received_items.len()
+                    && recipe.try_crafting(&items).is_some()
+            })
+        };
+
+        if let Some(recipe) = chosen_recipe {
+            self.current_recipe = Some(recipe.clone());
         }
     }
 }
@@ -38,6 +112,10 @@ impl MachineType for Crafter {
         mut output_items: Option<&mut OutputItems>,
         _middleground_object: Option<MiddlegroundObject>,
     ) {
+        // Insert the received modules
+
+        self.modules.insert_from(input_items.exactly_one_mut());
+
         // Crafting
 
         let current_recipe = match &self.current_recipe {
@@ -51,14 +129,17 @@ impl MachineType for Crafter {
         match self.crafting_time_left.as_mut() {
             Some((0, (output_item, output_count))) => {
                 // Crafting finished
-                // Append the crafted item to `output_items`
+                // Append the crafted item to `output_items`, twice if the productivity modules earned a free batch
+                let batches = if self.modules.finish_craft() { 2 } else { 1 };
 
-                for _ in 0..*output_count {
+                for _ in 0..*output_count * batches {
                     output_items
                         .unwrap_single_side_mut()
                         .push_back((*output_item).into());
                 }
 
+                self.produced_items
+                    .push((*output_item, *output_count as u32 * batches as u32));
                 self.crafting_time_left = None;
             }
 
@@ -100,7 +181,9 @@ impl MachineType for Crafter {
                 }
 
                 self.crafting_time_left = Some((
-                    current_recipe.crafting_time,
+                    self.modules
+                        .crafting_time(current_recipe.crafting_time as u32)
+                        as u16,
                     (current_recipe.output_item, current_recipe.output_count),
                 ));
             }
@@ -114,10 +197,46 @@ impl MachineType for Crafter {
         _output_items: Option<&OutputItems>,
         _input_side: &Side,
     ) -> bool {
+        // Only take as many modules as there are free slots
+        if ModuleType::from_item(*item).is_some() {
+            return input_items
+                .all()
+                .into_iter()
+                .filter(|input_item| ModuleType::from_item(***input_item).is_some())
+                .count()
+                < self.modules.free_slots();
+        }
+
         input_items.count_item(item) < 50
     }
 
     fn is_selectable(&self) -> bool {
         true
     }
+
+    fn power_role(&self) -> PowerRole {
+        PowerRole::Consumer(self.modules.power_consumption(Self::POWER_CONSUMPTION))
+    }
+
+    fn modules(&self) -> Option<&ModuleSlots> {
+        Some(&self.modules)
+    }
+
+    fn modules_mut(&mut self) -> Option<&mut ModuleSlots> {
+        Some(&mut self.modules)
+    }
+
+    fn take_produced_items(&mut self) -> Vec<(ItemType, u32)> {
+        std::mem::take(&mut self.produced_items)
+    }
+
+    fn progress(&self) -> Option<f32> {
+        let (crafting_time_left, _) = self.crafting_time_left?;
+        let crafting_time = self
+            .modules
+            .crafting_time(self.current_recipe.as_ref()?.crafting_time as u32);
+
+        // The speed modules can change while crafting
+        Some((1.0 - crafting_time_left as f32 / crafting_time.max(1) as f32).clamp(0.0, 1.0))
+    }
 }
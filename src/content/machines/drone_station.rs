@@ -0,0 +1,365 @@
+use bevy::math::Vec2;
+use bevy_ecs_tilemap::prelude::*;
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter};
+
+use crate::{
+    content::{
+        items::ItemType,
+        machine_types::{
+            InputItems, ItemSlot, MachineSetting, MachineType, OutputItems, Side,
+            UnwrapOutputItemsMut,
+        },
+    },
+    plugins::world::MiddlegroundObject,
+};
+
+const ALL_SIDES: [Side; 4] = [Side::North, Side::East, Side::South, Side::West];
+
+/// Whether a drone station sends its items away or receives items from other stations
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Display, EnumIter)]
+pub enum DroneStationMode {
+    #[default]
+    Send,
+    Receive,
+}
+
+/// A drone carrying items to a receiving station
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DroneFlight {
+    /// The station the drone started at
+    pub from: TilePos,
+
+    /// How many ticks the whole flight takes
+    pub flight_time: u32,
+
+    /// How many ticks are left until the drone arrives
+    pub ticks_left: u32,
+
+    pub items: Vec<ItemType>,
+}
+
+impl DroneFlight {
+    /// How much of the flight is done, from 0 to 1
+    pub fn progress(&self) -> f32 {
+        1.0 - self.ticks_left as f32 / self.flight_time as f32
+    }
+}
+
+/// Stores the items it gets and lets drones carry them to receiving stations on the channels of its route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DroneStation {
+    pub mode: DroneStationMode,
+
+    /// The channel a receiving station listens on and the first stop of a sending station's route
+    pub channel: u8,
+
+    /// The channels a sending station sends its drones to after [DroneStation::channel], `None` for unused stops
+    #[serde(default)]
+    later_stops: [Option<u8>; Self::ROUTE_LENGTH - 1],
+
+    /// The stop of the route the next drone flies to
+    #[serde(default)]
+    next_stop: usize,
+
+    slots: Vec<ItemSlot>,
+
+    /// The slot the next item is taken out of
+    next_slot_index: usize,
+
+    /// Ticks until the drone of a sending station is back and can start again
+    drone_returns_in: u32,
+
+    /// The drones on their way to a receiving station
+    incoming: Vec<DroneFlight>,
+
+    last_side: Side,
+}
+
+impl DroneStation {
+    const SLOT_COUNT: usize = 4;
+    const CHANNELS: u8 = 8;
+
+    /// How many stops the route of a sending station can have
+    const ROUTE_LENGTH: usize = 4;
+
+    /// How many items a drone can carry at once
+    const CARGO_SIZE: usize = 20;
+
+    /// How many ticks a drone needs for one tile
+    const TICKS_PER_TILE: f32 = 1.5;
+
+    /// How many drones can be on their way to a receiving station at once
+    pub const MAX_INCOMING: usize = 2;
+
+    /// How many items can wait in the output to be moved to the machines around
+    const OUTPUT_BUFFER_SIZE: usize = 4;
+
+    pub fn new() -> Self {
+        Self {
+            mode: DroneStationMode::Send,
+            channel: 0,
+            later_stops: [None; Self::ROUTE_LENGTH - 1],
+            next_stop: 0,
+            slots: vec![ItemSlot::default(); Self::SLOT_COUNT],
+            next_slot_index: 0,
+            drone_returns_in: 0,
+            incoming: Vec::new(),
+            last_side: Side::North,
+        }
+    }
+
+    /// How many ticks a drone needs to fly between two stations
+    pub fn flight_time(from: TilePos, to: TilePos) -> u32 {
+        let distance = Vec2::new(from.x as f32 - to.x as f32, from.y as f32 - to.y as f32).length();
+
+        ((distance * Self::TICKS_PER_TILE).ceil() as u32).max(1)
+    }
+
+    /// The channels of the stops a sending station's drones fly to, one after another
+    pub fn route(&self) -> Vec<u8> {
+        std::iter::once(self.channel)
+            .chain(self.later_stops.iter().flatten().copied())
+            .collect()
+    }
+
+    /// The channel of the stop the next drone flies to
+    pub fn current_stop(&self) -> u8 {
+        let route = self.route();
+
+        route[self.next_stop % route.len()]
+    }
+
+    /// Whether the drone of this sending station is back and there is something to carry
+    pub fn is_ready_to_send(&self) -> bool {
+        self.mode == DroneStationMode::Send
+            && self.drone_returns_in == 0
+            && self.slots.iter().any(|slot| slot.item.is_some())
+    }
+
+    /// Whether another drone can be sent to this receiving station
+    pub fn can_receive(&self) -> bool {
+        self.mode == DroneStationMode::Receive && self.incoming.len() < Self::MAX_INCOMING
+    }
+
+    /// The drones on their way to this station
+    pub fn incoming(&self) -> &[DroneFlight] {
+        &self.incoming
+    }
+
+    /// Load the drone with up to [DroneStation::CARGO_SIZE] items
+    ///
+    /// The drone is gone until it flew there and back again, the next one flies to the next stop of the route.
+    pub fn take_cargo(&mut self, flight_time: u32) -> Vec<ItemType> {
+        let mut cargo = Vec::new();
+
+        while cargo.len() < Self::CARGO_SIZE
+            && let Some(item) = self.take_next_item()
+        {
+            cargo.push(item);
+        }
+
+        self.drone_returns_in = flight_time * 2;
+        self.next_stop = (self.next_stop + 1) % self.route().len();
+
+        cargo
+    }
+
+    /// Send a drone on its way to this station
+    pub fn receive(&mut self, flight: DroneFlight) {
+        self.incoming.push(flight);
+    }
+
+    /// Find the slot an item of type `item` should be stored in
+    ///
+    /// Slots already holding this item are filled up first.
+    fn slot_for(&mut self, item: ItemType) -> Option<&mut ItemSlot> {
+        let index = self
+            .slots
+            .iter()
+            .position(|slot| slot.item == Some(item) && slot.free_space(item) > 0)
+            .or_else(|| self.slots.iter().position(|slot| slot.free_space(item) > 0))?;
+
+        Some(&mut self.slots[index])
+    }
+
+    /// Take one item out of the slots, going through them one after another
+    fn take_next_item(&mut self) -> Option<ItemType> {
+        for offset in 0..self.slots.len() {
+            let index = (self.next_slot_index + offset) % self.slots.len();
+
+            if let Some(item) = self.slots[index].take() {
+                self.next_slot_index = (index + 1) % self.slots.len();
+                return Some(item);
+            }
+        }
+
+        None
+    }
+
+    /// Unload the drones that arrived, as far as their items fit into the slots
+    fn unload_drones(&mut self) {
+        for flight in &mut self.incoming {
+            flight.ticks_left = flight.ticks_left.saturating_sub(1);
+        }
+
+        let mut incoming = std::mem::take(&mut self.incoming);
+
+        for flight in incoming.iter_mut().filter(|flight| flight.ticks_left == 0) {
+            while let Some(&item) = flight.items.last()
+                && let Some(slot) = self.slot_for(item)
+            {
+                slot.insert(item);
+                flight.items.pop();
+            }
+        }
+
+        // Drones that couldn't unload everything wait at the station
+        incoming.retain(|flight| flight.ticks_left > 0 || !flight.items.is_empty());
+        self.incoming = incoming;
+    }
+}
+
+#[typetag::serde]
+impl MachineType for DroneStation {
+    fn perform_action(
+        &mut self,
+        input_items: &mut InputItems,
+        mut output_items: Option<&mut OutputItems>,
+        _middleground_object: Option<MiddlegroundObject>,
+    ) {
+        self.drone_returns_in = self.drone_returns_in.saturating_sub(1);
+
+        // Store the received items
+        for side in ALL_SIDES {
+            let side_items = input_items
+                .get_side_mut(&side)
+                .expect("Drone station should have all inputs");
+
+            while let Some(item) = side_items.front()
+                && let Some(slot) = self.slot_for(**item)
+            {
+                slot.insert(**item);
+                side_items.pop_front();
+            }
+        }
+
+        // Drones that were on their way when the station was switched to sending still unload here
+        self.unload_drones();
+
+        if self.mode == DroneStationMode::Send {
+            return;
+        }
+
+        let sides = match self.last_side {
+            Side::North => [Side::North, Side::East, Side::South, Side::West],
+            Side::East => [Side::East, Side::South, Side::West, Side::North],
+            Side::South => [Side::South, Side::West, Side::North, Side::East],
+            Side::West => [Side::West, Side::North, Side::East, Side::South],
+        };
+
+        let output_items = output_items.unwrap_multiple_sides_mut();
+        output_items.preferred_sides.clear();
+
+        for side in sides {
+            output_items.push_side(side);
+        }
+
+        while output_items.items.len() < Self::OUTPUT_BUFFER_SIZE
+            && let Some(item) = self.take_next_item()
+        {
+            output_items.items.push_back(item.into());
+        }
+
+        if !output_items.items.is_empty() {
+            self.last_side = match self.last_side {
+                Side::North => Side::East,
+                Side::East => Side::South,
+                Side::South => Side::West,
+                Side::West => Side::North,
+            };
+        }
+    }
+
+    fn can_accept(
+        &self,
+        item: &ItemType,
+        input_items: &InputItems,
+        _output_items: Option<&OutputItems>,
+        _input_side: &Side,
+    ) -> bool {
+        if self.mode == DroneStationMode::Receive {
+            return false;
+        }
+
+        let free_space: usize = self
+            .slots
+            .iter()
+            .map(|slot| slot.free_space(*item) as usize)
+            .sum();
+
+        // Items still waiting to be stored take up space too
+        free_space > input_items.count_item(item)
+    }
+
+    fn is_selectable(&self) -> bool {
+        true
+    }
+
+    fn settings(&self) -> Vec<MachineSetting> {
+        let channels = || {
+            (1..=Self::CHANNELS)
+                .map(|channel| channel.to_string())
+                .collect::<Vec<String>>()
+        };
+
+        let mut settings = vec![
+            MachineSetting::new(
+                "Mode",
+                DroneStationMode::iter()
+                    .map(|mode| mode.to_string())
+                    .collect(),
+                DroneStationMode::iter()
+                    .position(|mode| mode == self.mode)
+                    .unwrap_or(0),
+            ),
+            MachineSetting::new("Channel", channels(), self.channel as usize),
+        ];
+
+        // The later stops only matter for sending stations, "None" skips a stop
+        for (index, stop) in self.later_stops.iter().enumerate() {
+            settings.push(MachineSetting::new(
+                format!("Stop {}", index + 2),
+                std::iter::once(String::from("None"))
+                    .chain(channels())
+                    .collect(),
+                stop.map_or(0, |channel| channel as usize + 1),
+            ));
+        }
+
+        settings
+    }
+
+    fn apply_setting(&mut self, setting: usize, option: usize) {
+        match setting {
+            0 => {
+                self.mode = DroneStationMode::iter()
+                    .nth(option)
+                    .expect("The option should be one of the modes")
+            }
+            1 => self.channel = option as u8,
+            stop if stop - 2 < self.later_stops.len() => {
+                self.later_stops[stop - 2] = option.checked_sub(1).map(|channel| channel as u8);
+            }
+            _ => unreachable!(),
+        }
+
+        // Start the changed route from its first stop
+        self.next_stop = 0;
+    }
+
+    fn inventory(&self) -> Option<&[ItemSlot]> {
+        Some(&self.slots)
+    }
+}
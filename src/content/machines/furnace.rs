@@ -8,6 +8,7 @@ use crate::{
         machine_types::{
             InputItems, MachineType, OutputItems, Side, UnwrapOutputItems, UnwrapOutputItemsMut,
         },
+        modules::{ModuleSlots, ModuleType},
     },
     plugins::{crafting::recipe_types::FurnaceRecipe, world::MiddlegroundObject},
 };
@@ -17,7 +18,7 @@ pub struct Furnace {
     pub current_recipe: Option<FurnaceRecipe>,
 
     /// burn time left
-    burn_time: u8,
+    burn_time: u16,
 
     /// Crafting time left
     /// `None` if nothing is currently being crafting
@@ -26,24 +27,37 @@ pub struct Furnace {
     /// The side where items are inputted
     input_side: Side,
 
-    /// The side where coal is inputted
-    coal_input_side: Side,
+    /// The side where fuel is inputted
+    #[serde(alias = "coal_input_side")]
+    fuel_input_side: Side,
+
+    #[serde(default)]
+    modules: ModuleSlots,
+
+    /// Items finished since they were last counted for the statistics
+    #[serde(skip)]
+    produced_items: Vec<(ItemType, u32)>,
 }
 
 impl Furnace {
-    const MAX_BURN_TIME: u8 = 100;
-    const COAL_BURN_TIME: u8 = 60;
-    const SMELTING_BURN_TIME: u8 = 15;
+    pub const MAX_BURN_TIME: u16 = 100;
 
-    pub fn new(input_side: Side, coal_input_side: Side) -> Self {
+    pub fn new(input_side: Side, fuel_input_side: Side) -> Self {
         Self {
             current_recipe: None,
             burn_time: 0,
             crafting_time_left: None,
             input_side,
-            coal_input_side,
+            fuel_input_side,
+            modules: ModuleSlots::default(),
+            produced_items: Vec::new(),
         }
     }
+
+    /// The burn time that is currently stored in this furnace
+    pub fn burn_time(&self) -> u16 {
+        self.burn_time
+    }
 }
 
 #[typetag::serde]
@@ -54,24 +68,39 @@ impl MachineType for Furnace {
         mut output_items: Option<&mut OutputItems>,
         _middleground_object: Option<MiddlegroundObject>,
     ) {
-        // Convert the coal to burn time
+        // Convert the fuel to burn time
 
-        let coal_input = input_items
-            .get_side_mut(&self.coal_input_side)
+        let fuel_input = input_items
+            .get_side_mut(&self.fuel_input_side)
             .unwrap_or_else(|| {
                 panic!(
-                    "A Furnace should have the coal input at {:?}",
-                    self.coal_input_side
+                    "A Furnace should have the fuel input at {:?}",
+                    self.fuel_input_side
                 )
             });
 
-        // We already know, there's only coal in here
-        if self.burn_time + Self::COAL_BURN_TIME <= Self::MAX_BURN_TIME
-            && coal_input.pop_front().is_some()
+        // We already know, there's only fuel in here
+        // An empty furnace always takes the fuel, even if it's worth more than the maximum burn time
+        if let Some(fuel_value) = fuel_input.front().and_then(|fuel| fuel.fuel_value())
+            && (self.burn_time == 0 || self.burn_time + fuel_value <= Self::MAX_BURN_TIME)
         {
-            self.burn_time += Self::COAL_BURN_TIME;
+            fuel_input.pop_front();
+            self.burn_time += fuel_value;
         }
 
+        // Insert the received modules
+
+        self.modules.insert_from(
+            input_items
+                .get_side_mut(&self.input_side)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "A Furnace should have the item input at {:?}",
+                        self.input_side
+                    )
+                }),
+        );
+
         // Smelting
 
         let current_recipe = match &self.current_recipe {
@@ -83,13 +112,17 @@ impl MachineType for Furnace {
         match self.crafting_time_left.as_mut() {
             Some((0, (output_item, output_count))) => {
                 // Smelting finished
-                // Append the smelted item to `output_items`
-                for _ in 0..*output_count {
+                // Append the smelted item to `output_items`, twice if the productivity modules earned a free batch
+                let batches = if self.modules.finish_craft() { 2 } else { 1 };
+
+                for _ in 0..*output_count * batches {
                     output_items
                         .unwrap_single_side_mut()
                         .push_back((*output_item).into());
                 }
 
+                self.produced_items
+                    .push((*output_item, *output_count as u32 * batches as u32));
                 self.crafting_time_left = None;
             }
 
@@ -98,14 +131,20 @@ impl MachineType for Furnace {
             }
 
             None => {
+                let energy = self.modules.energy(current_recipe.energy as u32) as u16;
+
                 // Only try to craft something, if there are no items already crafted and enough burn time is left
-                if self.burn_time >= Self::SMELTING_BURN_TIME
-                    && output_items.unwrap_single_side().is_empty()
-                {
+                if self.burn_time >= energy && output_items.unwrap_single_side().is_empty() {
                     let mut items = HashMap::new();
-                    let items_input = input_items
-                        .get_side_mut(&self.input_side)
-                        .expect("A Furnace should have a north input");
+                    let items_input =
+                        input_items
+                            .get_side_mut(&self.input_side)
+                            .unwrap_or_else(|| {
+                                panic!(
+                                    "A Furnace should have the item input at {:?}",
+                                    self.input_side
+                                )
+                            });
 
                     // Convert the queue into a HashMap of all the items and their count
                     for item in items_input.iter() {
@@ -123,7 +162,7 @@ impl MachineType for Furnace {
                     };
 
                     items_input.clear();
-                    self.burn_time -= Self::SMELTING_BURN_TIME;
+                    self.burn_time -= energy;
 
                     // Transfer the `rest_items` back into `items_input`
                     for (item, count) in rest_items {
@@ -132,8 +171,10 @@ impl MachineType for Furnace {
                         }
                     }
 
-                    self.crafting_time_left =
-                        Some((current_recipe.burn_time, current_recipe.output_item));
+                    self.crafting_time_left = Some((
+                        self.modules.crafting_time(current_recipe.burn_time as u32) as u8,
+                        current_recipe.output_item,
+                    ));
                 }
             }
         }
@@ -146,7 +187,16 @@ impl MachineType for Furnace {
         _output_items: Option<&OutputItems>,
         input_side: &Side,
     ) -> bool {
-        if *input_side == self.input_side {
+        if *input_side == self.input_side && ModuleType::from_item(*item).is_some() {
+            // Only take as many modules as there are free slots
+            input_items
+                .get_side(input_side)
+                .expect("This side should exist")
+                .iter()
+                .filter(|&side_item| ModuleType::from_item(**side_item).is_some())
+                .count()
+                < self.modules.free_slots()
+        } else if *input_side == self.input_side {
             input_items
                 .get_side(input_side)
                 .expect("This side should exist")
@@ -154,8 +204,8 @@ impl MachineType for Furnace {
                 .filter(|&side_item| **side_item == *item)
                 .count()
                 < 50
-        } else if *input_side == self.coal_input_side {
-            *item == ItemType::Coal
+        } else if *input_side == self.fuel_input_side {
+            item.fuel_value().is_some()
                 && input_items
                     .get_side(input_side)
                     .expect("This side should exist")
@@ -169,4 +219,26 @@ impl MachineType for Furnace {
     fn is_selectable(&self) -> bool {
         true
     }
+
+    fn modules(&self) -> Option<&ModuleSlots> {
+        Some(&self.modules)
+    }
+
+    fn modules_mut(&mut self) -> Option<&mut ModuleSlots> {
+        Some(&mut self.modules)
+    }
+
+    fn take_produced_items(&mut self) -> Vec<(ItemType, u32)> {
+        std::mem::take(&mut self.produced_items)
+    }
+
+    fn progress(&self) -> Option<f32> {
+        let (crafting_time_left, _) = self.crafting_time_left?;
+        let crafting_time = self
+            .modules
+            .crafting_time(self.current_recipe.as_ref()?.burn_time as u32);
+
+        // The speed modules can change while smelting
+        Some((1.0 - crafting_time_left as f32 / crafting_time.max(1) as f32).clamp(0.0, 1.0))
+    }
 }
@@ -0,0 +1,91 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    content::{
+        items::ItemType,
+        machine_types::{InputItems, MachineType, OutputItems, PowerRole, Side},
+    },
+    plugins::world::MiddlegroundObject,
+};
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct Generator {
+    /// burn time left
+    burn_time: u16,
+
+    /// The share of the production currently used by the power network
+    #[serde(skip)]
+    load: f32,
+
+    /// Burn time that was used up, but not yet subtracted from `burn_time`
+    #[serde(skip)]
+    burn_progress: f32,
+}
+
+impl Generator {
+    pub const POWER_PRODUCTION: u32 = 40;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[typetag::serde]
+impl MachineType for Generator {
+    fn perform_action(
+        &mut self,
+        input_items: &mut InputItems,
+        _output_items: Option<&mut OutputItems>,
+        _middleground_object: Option<MiddlegroundObject>,
+    ) {
+        // Only burn as much fuel as the network actually needs
+        if self.burn_time > 0 {
+            self.burn_progress += self.load;
+
+            while self.burn_progress >= 1.0 && self.burn_time > 0 {
+                self.burn_progress -= 1.0;
+                self.burn_time -= 1;
+            }
+        }
+
+        // Refuel right away, so the power supply doesn't drop for a tick
+        if self.burn_time == 0 {
+            for side in [Side::North, Side::East, Side::South, Side::West] {
+                let fuel_input = input_items
+                    .get_side_mut(&side)
+                    .expect("Generator should have all inputs");
+
+                // We already know, there's only fuel in here
+                if let Some(fuel_value) = fuel_input.front().and_then(|fuel| fuel.fuel_value()) {
+                    fuel_input.pop_front();
+                    self.burn_time = fuel_value;
+                    self.burn_progress = 0.0;
+
+                    break;
+                }
+            }
+        }
+    }
+
+    fn can_accept(
+        &self,
+        item: &ItemType,
+        input_items: &InputItems,
+        _output_items: Option<&OutputItems>,
+        _input_side: &Side,
+    ) -> bool {
+        item.fuel_value().is_some() && input_items.count() < 20
+    }
+
+    fn power_role(&self) -> PowerRole {
+        if self.burn_time > 0 {
+            PowerRole::Producer(Self::POWER_PRODUCTION)
+        } else {
+            PowerRole::Producer(0)
+        }
+    }
+
+    fn set_power_load(&mut self, load: f32) {
+        self.load = load;
+    }
+}
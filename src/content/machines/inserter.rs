@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    content::{
+        items::ItemType,
+        machine_types::{
+            InputItems, MachineSetting, MachineType, OutputItems, Side, UnwrapOutputItems,
+            UnwrapOutputItemsMut,
+        },
+        signals::SignalCondition,
+    },
+    plugins::world::MiddlegroundObject,
+};
+
+/// Grabs items from the machine behind it and inserts them into the machine in front of it,
+/// no matter which sides these machines take and give their items on
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct Inserter {
+    /// Only items of this type are moved, `None` moves everything
+    pub filter: Option<ItemType>,
+
+    /// How many items are moved at once
+    pub stack_size: u8,
+
+    /// Time left until the inserter can grab the next items
+    swing_time: u8,
+
+    /// The inserter only runs while the signals around it meet this condition
+    #[serde(default)]
+    pub condition: SignalCondition,
+}
+
+impl Inserter {
+    const SWING_TIME: u8 = 5;
+    const STACK_SIZES: [u8; 4] = [1, 2, 4, 8];
+
+    pub fn new() -> Self {
+        Self {
+            filter: None,
+            stack_size: 1,
+            swing_time: 0,
+            condition: SignalCondition::default(),
+        }
+    }
+}
+
+#[typetag::serde]
+impl MachineType for Inserter {
+    fn perform_action(
+        &mut self,
+        input_items: &mut InputItems,
+        mut output_items: Option<&mut OutputItems>,
+        _middleground_object: Option<MiddlegroundObject>,
+    ) {
+        if self.swing_time > 0 {
+            self.swing_time -= 1;
+            return;
+        }
+
+        // Only grab new items if the last ones were inserted
+        if !output_items.unwrap_single_side().is_empty() {
+            return;
+        }
+
+        let input_items = input_items.exactly_one_mut();
+
+        if input_items.is_empty() {
+            return;
+        }
+
+        for _ in 0..self.stack_size {
+            let Some(item) = input_items.pop_front() else {
+                break;
+            };
+
+            output_items.unwrap_single_side_mut().push_back(item);
+        }
+
+        self.swing_time = Self::SWING_TIME;
+    }
+
+    fn can_accept(
+        &self,
+        item: &ItemType,
+        input_items: &InputItems,
+        _output_items: Option<&OutputItems>,
+        _input_side: &Side,
+    ) -> bool {
+        self.filter.is_none_or(|filter| filter == *item)
+            && input_items.count() < self.stack_size as usize
+    }
+
+    fn is_selectable(&self) -> bool {
+        true
+    }
+
+    fn connects_to_any_side(&self) -> bool {
+        true
+    }
+
+    fn settings(&self) -> Vec<MachineSetting> {
+        let mut settings = vec![
+            MachineSetting::item_filter("Filter", self.filter),
+            MachineSetting::new(
+                "Stack Size",
+                Self::STACK_SIZES
+                    .iter()
+                    .map(|stack_size| stack_size.to_string())
+                    .collect(),
+                Self::STACK_SIZES
+                    .iter()
+                    .position(|&stack_size| stack_size == self.stack_size)
+                    .unwrap_or(0),
+            ),
+        ];
+
+        settings.extend(self.condition.settings());
+        settings
+    }
+
+    fn apply_setting(&mut self, setting: usize, option: usize) {
+        match setting {
+            0 => self.filter = MachineSetting::item_filter_option(option),
+            1 => self.stack_size = Self::STACK_SIZES[option],
+            _ => self.condition.apply_setting(setting - 2, option),
+        }
+    }
+
+    fn signal_condition(&self) -> Option<&SignalCondition> {
+        Some(&self.condition)
+    }
+}
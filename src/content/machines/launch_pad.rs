@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    content::{
+        items::ItemType,
+        machine_types::{InputItems, MachineType, OutputItems, Side},
+    },
+    plugins::world::MiddlegroundObject,
+};
+
+const ALL_SIDES: [Side; 4] = [Side::North, Side::East, Side::South, Side::West];
+
+/// Takes the items that end the game and launches them
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LaunchPad {
+    /// How many launches happened since they were last collected
+    #[serde(skip)]
+    launches: u32,
+}
+
+impl LaunchPad {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take the amount of launches since the last call
+    pub fn take_launches(&mut self) -> u32 {
+        std::mem::take(&mut self.launches)
+    }
+}
+
+#[typetag::serde]
+impl MachineType for LaunchPad {
+    fn perform_action(
+        &mut self,
+        input_items: &mut InputItems,
+        _output_items: Option<&mut OutputItems>,
+        _middleground_object: Option<MiddlegroundObject>,
+    ) {
+        for side in ALL_SIDES {
+            let side_items = input_items
+                .get_side_mut(&side)
+                .expect("Launch pad should have all inputs");
+
+            self.launches += side_items.len() as u32;
+            side_items.clear();
+        }
+    }
+
+    fn can_accept(
+        &self,
+        item: &ItemType,
+        input_items: &InputItems,
+        _output_items: Option<&OutputItems>,
+        _input_side: &Side,
+    ) -> bool {
+        item.ends_game() && input_items.count() == 0
+    }
+
+    fn tick_after_first(&self) -> bool {
+        true
+    }
+}
@@ -4,23 +4,70 @@ use crate::{
     content::{
         items::ItemType,
         machine_types::{
-            InputItems, MachineType, OutputItems, Side, UnwrapOutputItems, UnwrapOutputItemsMut,
+            InputItems, MachineType, OutputItems, PowerRole, Side, UnwrapOutputItems,
+            UnwrapOutputItemsMut,
         },
     },
     plugins::world::MiddlegroundObject,
 };
 
-#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+/// The different kinds of miners
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MinerTier {
+    #[default]
+    Basic,
+    Advanced,
+}
+
+impl MinerTier {
+    /// The amount of ticks needed to mine a single item
+    pub fn mining_time(&self) -> u8 {
+        match self {
+            MinerTier::Basic => 30,
+            MinerTier::Advanced => 12,
+        }
+    }
+
+    /// The amount of power needed while mining
+    pub fn power_consumption(&self) -> u32 {
+        match self {
+            MinerTier::Basic => 5,
+            MinerTier::Advanced => 15,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Miner {
     /// time spent mining the current resource
     mining_time: Option<u8>,
+    #[serde(default)]
+    tier: MinerTier,
+    /// items mined since the ore deposit under the miner was last updated
+    #[serde(skip)]
+    mined_items: u32,
+    /// items mined since they were last counted for the statistics
+    #[serde(skip)]
+    produced_items: Vec<(ItemType, u32)>,
 }
 
 impl Miner {
-    const MINING_TIME: u8 = 30;
-
     pub fn new() -> Self {
-        Self { mining_time: None }
+        Self::with_tier(MinerTier::Basic)
+    }
+
+    pub fn with_tier(tier: MinerTier) -> Self {
+        Self {
+            mining_time: None,
+            tier,
+            mined_items: 0,
+            produced_items: Vec::new(),
+        }
+    }
+
+    /// Returns the amount of items mined since the last call and resets it
+    pub fn take_mined_items(&mut self) -> u32 {
+        std::mem::take(&mut self.mined_items)
     }
 }
 
@@ -39,15 +86,14 @@ impl MachineType for Miner {
         {
             match &mut self.mining_time {
                 Some(0) => {
-                    let item = match middleground_object {
-                        MiddlegroundObject::Coal => ItemType::Coal,
-                        MiddlegroundObject::Copper => ItemType::RawCopper,
-                        MiddlegroundObject::Iron => ItemType::RawIron,
-                    };
-
                     // Append the resource under the miner
-                    output_items.unwrap_single_side_mut().push_back(item.into());
+                    output_items
+                        .unwrap_single_side_mut()
+                        .push_back(middleground_object.mined_item().into());
 
+                    self.mined_items += 1;
+                    self.produced_items
+                        .push((middleground_object.mined_item(), 1));
                     self.mining_time = None;
                 }
                 // Reduce the mining timer by one if it is set
@@ -56,7 +102,7 @@ impl MachineType for Miner {
                 }
                 // If there are no other items in the output, reset the timer
                 None if output_items.unwrap_single_side().is_empty() => {
-                    self.mining_time = Some(Self::MINING_TIME);
+                    self.mining_time = Some(self.tier.mining_time());
                 }
                 // else do nothing and try again next time
                 None => {}
@@ -73,4 +119,18 @@ impl MachineType for Miner {
     ) -> bool {
         unreachable!()
     }
+
+    fn power_role(&self) -> PowerRole {
+        PowerRole::Consumer(self.tier.power_consumption())
+    }
+
+    fn take_produced_items(&mut self) -> Vec<(ItemType, u32)> {
+        std::mem::take(&mut self.produced_items)
+    }
+
+    fn progress(&self) -> Option<f32> {
+        let mining_time = self.mining_time?;
+
+        Some(1.0 - mining_time as f32 / self.tier.mining_time() as f32)
+    }
 }
@@ -2,6 +2,14 @@ pub mod belt;
 pub mod chest;
 pub mod combiner;
 pub mod crafter;
+pub mod drone_station;
 pub mod furnace;
+pub mod generator;
+pub mod inserter;
+pub mod launch_pad;
 pub mod miner;
+pub mod power_pole;
+pub mod signal_pole;
+pub mod sink;
+pub mod solar_panel;
 pub mod splitter;
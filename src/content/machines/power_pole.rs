@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    content::{
+        items::ItemType,
+        machine_types::{InputItems, MachineType, OutputItems, PowerRole, Side},
+    },
+    plugins::world::MiddlegroundObject,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct PowerPole;
+
+impl PowerPole {
+    /// How many tiles around the pole machines get connected
+    pub const SUPPLY_RANGE: u32 = 2;
+}
+
+#[typetag::serde]
+impl MachineType for PowerPole {
+    fn perform_action(
+        &mut self,
+        _input_items: &mut InputItems,
+        _output_items: Option<&mut OutputItems>,
+        _middleground_object: Option<MiddlegroundObject>,
+    ) {
+    }
+
+    fn can_accept(
+        &self,
+        _item: &ItemType,
+        _input_items: &InputItems,
+        _output_items: Option<&OutputItems>,
+        _input_side: &Side,
+    ) -> bool {
+        unreachable!()
+    }
+
+    fn power_role(&self) -> PowerRole {
+        PowerRole::Pole(Self::SUPPLY_RANGE)
+    }
+}
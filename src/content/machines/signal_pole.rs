@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    content::{
+        items::ItemType,
+        machine_types::{InputItems, MachineType, OutputItems, Side},
+    },
+    plugins::world::MiddlegroundObject,
+};
+
+/// Reads the item counts of the machines around it and shares them with all poles it is wired to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SignalPole;
+
+impl SignalPole {
+    /// How many tiles around the pole machines are read and controlled
+    pub const RANGE: u32 = 2;
+}
+
+#[typetag::serde]
+impl MachineType for SignalPole {
+    fn perform_action(
+        &mut self,
+        _input_items: &mut InputItems,
+        _output_items: Option<&mut OutputItems>,
+        _middleground_object: Option<MiddlegroundObject>,
+    ) {
+    }
+
+    fn can_accept(
+        &self,
+        _item: &ItemType,
+        _input_items: &InputItems,
+        _output_items: Option<&OutputItems>,
+        _input_side: &Side,
+    ) -> bool {
+        unreachable!()
+    }
+}
@@ -0,0 +1,82 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    content::{
+        items::ItemType,
+        machine_types::{InputItems, MachineSetting, MachineType, OutputItems, Side},
+    },
+    plugins::world::MiddlegroundObject,
+};
+
+/// Destroys every item it receives and counts them
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Sink {
+    /// Only items of this type are accepted, `None` accepts everything
+    pub filter: Option<ItemType>,
+
+    /// The items destroyed since they were last collected
+    sunk_items: HashMap<ItemType, u32>,
+}
+
+impl Sink {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Take all the items destroyed since the last call
+    pub fn take_sunk_items(&mut self) -> HashMap<ItemType, u32> {
+        std::mem::take(&mut self.sunk_items)
+    }
+}
+
+#[typetag::serde]
+impl MachineType for Sink {
+    fn perform_action(
+        &mut self,
+        input_items: &mut InputItems,
+        _output_items: Option<&mut OutputItems>,
+        _middleground_object: Option<MiddlegroundObject>,
+    ) {
+        for item in input_items.all() {
+            *self.sunk_items.entry(**item).or_default() += 1;
+        }
+
+        for side in [Side::North, Side::East, Side::South, Side::West] {
+            input_items
+                .get_side_mut(&side)
+                .expect("Sink should have all inputs")
+                .clear();
+        }
+    }
+
+    fn can_accept(
+        &self,
+        item: &ItemType,
+        _input_items: &InputItems,
+        _output_items: Option<&OutputItems>,
+        _input_side: &Side,
+    ) -> bool {
+        self.filter.is_none_or(|filter| filter == *item)
+    }
+
+    fn is_selectable(&self) -> bool {
+        true
+    }
+
+    fn tick_after_first(&self) -> bool {
+        true
+    }
+
+    fn settings(&self) -> Vec<MachineSetting> {
+        vec![MachineSetting::item_filter("Filter", self.filter)]
+    }
+
+    fn apply_setting(&mut self, setting: usize, option: usize) {
+        match setting {
+            0 => self.filter = MachineSetting::item_filter_option(option),
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    content::{
+        items::ItemType,
+        machine_types::{InputItems, MachineType, OutputItems, PowerRole, Side},
+    },
+    plugins::world::MiddlegroundObject,
+};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct SolarPanel;
+
+impl SolarPanel {
+    pub const POWER_PRODUCTION: u32 = 5;
+}
+
+#[typetag::serde]
+impl MachineType for SolarPanel {
+    fn perform_action(
+        &mut self,
+        _input_items: &mut InputItems,
+        _output_items: Option<&mut OutputItems>,
+        _middleground_object: Option<MiddlegroundObject>,
+    ) {
+    }
+
+    fn can_accept(
+        &self,
+        _item: &ItemType,
+        _input_items: &InputItems,
+        _output_items: Option<&OutputItems>,
+        _input_side: &Side,
+    ) -> bool {
+        unreachable!()
+    }
+
+    fn power_role(&self) -> PowerRole {
+        // The desert sun never sets
+        PowerRole::Producer(Self::POWER_PRODUCTION)
+    }
+}
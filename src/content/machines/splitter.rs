@@ -3,7 +3,10 @@ use serde::{Deserialize, Serialize};
 use crate::{
     content::{
         items::ItemType,
-        machine_types::{InputItems, MachineType, OutputItems, Side, UnwrapOutputItemsMut},
+        machine_types::{
+            InputItems, MachineSetting, MachineType, OutputItems, Side, UnwrapOutputItemsMut,
+        },
+        signals::SignalCondition,
     },
     plugins::world::MiddlegroundObject,
 };
@@ -12,6 +15,26 @@ use crate::{
 pub struct Splitter {
     output_sides: [Side; 2],
     last_output_side_index: usize,
+
+    /// Items of this type are always sent to the output side at `filter_side_index`,
+    /// all other items to the other one
+    #[serde(default)]
+    pub filter: Option<ItemType>,
+
+    #[serde(default)]
+    pub filter_side_index: usize,
+
+    /// The index of the output side that gets items first, `None` alternates between both
+    #[serde(default)]
+    pub priority_side_index: Option<usize>,
+
+    /// Whether items can go to the other output side if their side is blocked
+    #[serde(default = "Splitter::default_overflow")]
+    pub overflow: bool,
+
+    /// The splitter only runs while the signals around it meet this condition
+    #[serde(default)]
+    pub condition: SignalCondition,
 }
 
 impl Splitter {
@@ -19,8 +42,17 @@ impl Splitter {
         Self {
             output_sides,
             last_output_side_index: 0,
+            filter: None,
+            filter_side_index: 0,
+            priority_side_index: None,
+            overflow: true,
+            condition: SignalCondition::default(),
         }
     }
+
+    fn default_overflow() -> bool {
+        true
+    }
 }
 
 #[typetag::serde]
@@ -38,8 +70,6 @@ impl MachineType for Splitter {
             _ => unreachable!(),
         };
 
-        let current_output_side = self.output_sides[current_output_side_index];
-
         //  Check if there are any items in the output and if something can be pulled from the input
         if output_items
             .as_ref()
@@ -47,6 +77,18 @@ impl MachineType for Splitter {
             .is_empty()
             && let Some(input_item) = input_items.exactly_one_mut().pop_front()
         {
+            // Filtered items go to the filter side, priority comes next and alternating is the fallback
+            let wanted_output_side_index = match (self.filter, self.priority_side_index) {
+                (Some(filter), _) if *input_item == filter => self.filter_side_index,
+                (Some(_), _) => 1 - self.filter_side_index,
+                (None, Some(priority_side_index)) => priority_side_index,
+                (None, None) => {
+                    // Switch last output side
+                    self.last_output_side_index = current_output_side_index;
+                    current_output_side_index
+                }
+            };
+
             // Get output_items (it should always be multiples sides)
             let output_items = output_items.unwrap_multiple_sides_mut();
             // Push item
@@ -54,11 +96,11 @@ impl MachineType for Splitter {
 
             // Set the preferred sides
             output_items.preferred_sides.clear();
-            output_items.push_side(current_output_side);
-            output_items.push_side(self.output_sides[self.last_output_side_index]);
+            output_items.push_side(self.output_sides[wanted_output_side_index]);
 
-            // Switch last output side
-            self.last_output_side_index = current_output_side_index;
+            if self.overflow {
+                output_items.push_side(self.output_sides[1 - wanted_output_side_index]);
+            }
         }
     }
 
@@ -76,7 +118,53 @@ impl MachineType for Splitter {
             < 1
     }
 
+    fn is_selectable(&self) -> bool {
+        true
+    }
+
     fn tick_after_first(&self) -> bool {
         true
     }
+
+    fn settings(&self) -> Vec<MachineSetting> {
+        let side_names: Vec<String> = self
+            .output_sides
+            .iter()
+            .map(|side| format!("{side:?}"))
+            .collect();
+
+        let mut settings = vec![
+            MachineSetting::item_filter("Filter", self.filter),
+            MachineSetting::new("Filter Side", side_names.clone(), self.filter_side_index),
+            MachineSetting::new(
+                "Output Priority",
+                std::iter::once(String::from("None"))
+                    .chain(side_names)
+                    .collect(),
+                self.priority_side_index.map_or(0, |index| index + 1),
+            ),
+            MachineSetting::new(
+                "Overflow",
+                vec![String::from("On"), String::from("Off")],
+                if self.overflow { 0 } else { 1 },
+            ),
+        ];
+
+        settings.extend(self.condition.settings());
+        settings
+    }
+
+    fn apply_setting(&mut self, setting: usize, option: usize) {
+        match setting {
+            0 => self.filter = MachineSetting::item_filter_option(option),
+            1 => self.filter_side_index = option,
+            2 => self.priority_side_index = option.checked_sub(1),
+            3 => self.overflow = option == 0,
+            _ => self.condition.apply_setting(setting - 4, option),
+        }
+    }
+
+    fn signal_condition(&self) -> Option<&SignalCondition> {
+        Some(&self.condition)
+    }
 }
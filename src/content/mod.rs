@@ -1,3 +1,5 @@
 pub mod items;
 pub mod machine_types;
 pub mod machines;
+pub mod modules;
+pub mod signals;
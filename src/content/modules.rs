@@ -0,0 +1,140 @@
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+use strum_macros::Display;
+
+use crate::content::items::{Item, ItemType};
+
+/// The upgrades that can be inserted into crafters and furnaces
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display)]
+pub enum ModuleType {
+    /// Shortens the crafting time, but uses more power
+    #[strum(to_string = "Speed Module")]
+    Speed,
+    /// Lowers the power or fuel needed
+    #[strum(to_string = "Efficiency Module")]
+    Efficiency,
+    /// Sometimes crafts an additional batch for free
+    #[strum(to_string = "Productivity Module")]
+    Productivity,
+}
+
+impl ModuleType {
+    /// Get the module an item can be inserted as
+    ///
+    /// Returns `None` if the item isn't a module.
+    pub fn from_item(item: ItemType) -> Option<Self> {
+        match item {
+            ItemType::SpeedModule => Some(Self::Speed),
+            ItemType::EfficiencyModule => Some(Self::Efficiency),
+            ItemType::ProductivityModule => Some(Self::Productivity),
+            _ => None,
+        }
+    }
+
+    /// The item this module turns back into when it's removed
+    pub fn item(&self) -> ItemType {
+        match self {
+            Self::Speed => ItemType::SpeedModule,
+            Self::Efficiency => ItemType::EfficiencyModule,
+            Self::Productivity => ItemType::ProductivityModule,
+        }
+    }
+}
+
+/// The module slots of a machine and the effects of the inserted modules
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ModuleSlots {
+    slots: [Option<ModuleType>; Self::SLOT_COUNT],
+
+    /// Progress towards the next free batch in percent
+    productivity_progress: u8,
+}
+
+impl ModuleSlots {
+    pub const SLOT_COUNT: usize = 2;
+
+    /// Each speed module takes away this percentage of the crafting time
+    const SPEED_BONUS: u32 = 25;
+    /// Each speed module adds this percentage to the power consumption
+    const SPEED_POWER_PENALTY: u32 = 50;
+    /// Each efficiency module takes away this percentage of the power or fuel needed
+    const EFFICIENCY_BONUS: u32 = 25;
+    /// Each productivity module adds this percentage of a batch to every craft
+    const PRODUCTIVITY_BONUS: u8 = 10;
+
+    pub fn slots(&self) -> &[Option<ModuleType>] {
+        &self.slots
+    }
+
+    /// The amount of empty slots
+    pub fn free_slots(&self) -> usize {
+        self.slots.iter().filter(|slot| slot.is_none()).count()
+    }
+
+    /// Put `module` into the first empty slot
+    ///
+    /// Returns `false` if all slots are taken.
+    pub fn insert(&mut self, module: ModuleType) -> bool {
+        match self.slots.iter_mut().find(|slot| slot.is_none()) {
+            Some(slot) => {
+                *slot = Some(module);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Take the module out of the slot at `slot`
+    pub fn remove(&mut self, slot: usize) -> Option<ModuleType> {
+        self.slots.get_mut(slot).and_then(Option::take)
+    }
+
+    fn count(&self, module: ModuleType) -> u32 {
+        self.slots
+            .iter()
+            .filter(|&&slot| slot == Some(module))
+            .count() as u32
+    }
+
+    /// Apply the speed modules to `crafting_time`
+    pub fn crafting_time(&self, crafting_time: u32) -> u32 {
+        crafting_time * (100 - Self::SPEED_BONUS * self.count(ModuleType::Speed)) / 100
+    }
+
+    /// Apply the efficiency modules to the fuel `energy` a recipe needs
+    pub fn energy(&self, energy: u32) -> u32 {
+        energy * (100 - Self::EFFICIENCY_BONUS * self.count(ModuleType::Efficiency)) / 100
+    }
+
+    /// Apply the speed and efficiency modules to `power_consumption`
+    pub fn power_consumption(&self, power_consumption: u32) -> u32 {
+        power_consumption
+            * (100 + Self::SPEED_POWER_PENALTY * self.count(ModuleType::Speed))
+            * (100 - Self::EFFICIENCY_BONUS * self.count(ModuleType::Efficiency))
+            / (100 * 100)
+    }
+
+    /// Add the progress of the productivity modules for a finished craft
+    ///
+    /// Returns `true` if a free batch was earned.
+    pub fn finish_craft(&mut self) -> bool {
+        self.productivity_progress +=
+            Self::PRODUCTIVITY_BONUS * self.count(ModuleType::Productivity) as u8;
+
+        if self.productivity_progress >= 100 {
+            self.productivity_progress -= 100;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Move all module items out of `items` into free slots
+    pub fn insert_from(&mut self, items: &mut VecDeque<Item>) {
+        items.retain(|item| match ModuleType::from_item(**item) {
+            Some(module) => !self.insert(module),
+            None => true,
+        });
+    }
+}
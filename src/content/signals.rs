@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter};
+
+use crate::content::{items::ItemType, machine_types::MachineSetting};
+
+/// How the item count on a signal network is compared to the value of a [SignalCondition]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, Display, EnumIter)]
+pub enum Comparison {
+    #[default]
+    #[strum(to_string = "<")]
+    Less,
+    #[strum(to_string = ">")]
+    Greater,
+    #[strum(to_string = "=")]
+    Equal,
+}
+
+/// A condition that has to be met by the signals of the networks around a machine for it to run
+///
+/// Machines with a condition without an item always run.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct SignalCondition {
+    pub item: Option<ItemType>,
+    pub comparison: Comparison,
+    pub value: u32,
+}
+
+impl SignalCondition {
+    /// The values that can be chosen in the settings menu
+    const VALUES: [u32; 8] = [0, 1, 10, 50, 100, 200, 500, 1000];
+
+    /// Check the condition against the item counts in `signals`
+    pub fn is_met(&self, signals: &HashMap<ItemType, u32>) -> bool {
+        let Some(item) = self.item else {
+            return true;
+        };
+
+        let count = signals.get(&item).copied().unwrap_or(0);
+
+        match self.comparison {
+            Comparison::Less => count < self.value,
+            Comparison::Greater => count > self.value,
+            Comparison::Equal => count == self.value,
+        }
+    }
+
+    /// The settings for choosing the condition, to be appended to a machine's own settings
+    pub fn settings(&self) -> Vec<MachineSetting> {
+        let item_options = std::iter::once(String::from("Always On"))
+            .chain(ItemType::iter().map(|item| item.to_string()))
+            .collect();
+        let item_selected = self
+            .item
+            .and_then(|condition_item| ItemType::iter().position(|item| item == condition_item))
+            .map_or(0, |position| position + 1);
+
+        vec![
+            MachineSetting::new("Run If Signal", item_options, item_selected),
+            MachineSetting::new(
+                "Comparison",
+                Comparison::iter()
+                    .map(|comparison| comparison.to_string())
+                    .collect(),
+                Comparison::iter()
+                    .position(|comparison| comparison == self.comparison)
+                    .unwrap_or(0),
+            ),
+            MachineSetting::new(
+                "Value",
+                Self::VALUES.iter().map(|value| value.to_string()).collect(),
+                Self::VALUES
+                    .iter()
+                    .position(|&value| value == self.value)
+                    .unwrap_or(0),
+            ),
+        ]
+    }
+
+    /// Select the option at `option` for the setting at `setting` of [SignalCondition::settings]
+    pub fn apply_setting(&mut self, setting: usize, option: usize) {
+        match setting {
+            0 => self.item = MachineSetting::item_filter_option(option),
+            1 => {
+                self.comparison = Comparison::iter()
+                    .nth(option)
+                    .expect("The option should be one of the comparisons")
+            }
+            2 => self.value = Self::VALUES[option],
+            _ => unreachable!(),
+        }
+    }
+}
@@ -3,38 +3,164 @@ use bevy_ecs_tilemap::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    content::machine_types::{InputItems, MachineType, OutputItems},
-    plugins::{building::foreground_objects::ForegroundObject, world::Seed},
+    content::machine_types::{InputItems, Machine, MachineType, OutputItems},
+    plugins::{
+        building::foreground_objects::ForegroundObject,
+        camera::CameraBookmarks,
+        completion::GameStatistics,
+        points::SinkStatistics,
+        world::{
+            CHUNK_SIZE, ChunkMachine, MAP_SIZE, Seed, WorldChunks, chunk_pos,
+            generation::{WorldGenerationConfig, WorldGenerator},
+        },
+    },
 };
 
+/// The version of the save format, raised whenever older saves need to be migrated
+const GAME_SAVE_VERSION: u32 = 1;
+
+/// How many tiles the map was across before it was split into chunks, in saves of version 0
+const LEGACY_MAP_SIZE: u32 = 64;
+
 #[derive(Resource, Deref, DerefMut, Default)]
 pub struct LoadedGameSave(Option<GameSave>);
 
 /// All the information saved to disk after closing the game are in this struct
 #[derive(Serialize, Deserialize)]
 pub struct GameSave {
-    pub machines: MachineTiles,
+    /// Saves from before the version was saved are version 0
+    #[serde(default)]
+    pub version: u32,
+    /// The machines of the whole map, only read from saves of version 0
+    ///
+    /// Newer saves keep the machines in their chunks.
+    #[serde(default, skip_serializing)]
+    machines: MachineTiles,
+    /// The tiles of the machines that run without power, see [Machine::legacy_power], only read from saves of version 0
+    ///
+    /// Saves from before power existed don't have this list, then all of their machines run without power.
+    #[serde(default, skip_serializing)]
+    legacy_powered_machines: Option<Vec<TilePos>>,
     pub seed: Seed,
     pub camera_translation: Vec3,
+    #[serde(default = "default_camera_scale")]
+    pub camera_scale: f32,
+    #[serde(default)]
+    pub camera_bookmarks: CameraBookmarks,
     pub has_completed_game: bool,
+    #[serde(default)]
+    pub sink_statistics: SinkStatistics,
+    #[serde(default)]
+    pub world_chunks: WorldChunks,
+    /// The ore left in every resource tile of the old map, only read from saves of version 0
+    #[serde(default, skip_serializing)]
+    ore_deposits: Vec<(TilePos, u32)>,
+    #[serde(default)]
+    pub world_generation_config: WorldGenerationConfig,
+    #[serde(default)]
+    pub game_statistics: GameStatistics,
 }
 
 impl GameSave {
     pub fn new(
-        machines: MachineTiles,
         seed: Seed,
         camera_translation: Vec3,
+        camera_scale: f32,
+        camera_bookmarks: CameraBookmarks,
         has_completed_game: bool,
+        sink_statistics: SinkStatistics,
+        world_chunks: WorldChunks,
+        world_generation_config: WorldGenerationConfig,
+        game_statistics: GameStatistics,
     ) -> Self {
         Self {
-            machines,
+            version: GAME_SAVE_VERSION,
+            machines: Vec::new(),
+            legacy_powered_machines: None,
             seed,
             camera_translation,
+            camera_scale,
+            camera_bookmarks,
             has_completed_game,
+            sink_statistics,
+            world_chunks,
+            ore_deposits: Vec::new(),
+            world_generation_config,
+            game_statistics,
+        }
+    }
+
+    /// Bring a save written by an older version of the game up to date
+    pub fn migrate(&mut self) {
+        if self.version == 0 {
+            // The old map is kept in the middle of the bigger one. Its tilemap was centered as well,
+            // so the world position of the camera still points at the same tiles.
+            let offset = (MAP_SIZE.x - LEGACY_MAP_SIZE) / 2;
+            let translate = |tile_pos: &TilePos| TilePos {
+                x: tile_pos.x + offset,
+                y: tile_pos.y + offset,
+            };
+
+            self.world_generation_config = WorldGenerationConfig {
+                legacy_area_size: LEGACY_MAP_SIZE,
+                ..default()
+            };
+
+            // Generate the chunks of the old map with the ore that was left in it
+            let world_generator = WorldGenerator::new(self.seed, self.world_generation_config);
+            let legacy_chunks =
+                (offset / CHUNK_SIZE) as i32..((offset + LEGACY_MAP_SIZE) / CHUNK_SIZE) as i32;
+
+            for x in legacy_chunks.clone() {
+                for y in legacy_chunks.clone() {
+                    let chunk_pos = IVec2::new(x, y);
+
+                    self.world_chunks
+                        .insert(chunk_pos, world_generator.chunk(chunk_pos));
+                }
+            }
+
+            for (tile_pos, amount) in std::mem::take(&mut self.ore_deposits) {
+                if let Some(ore_deposit) = self.world_chunks.ore_at_mut(&translate(&tile_pos)) {
+                    ore_deposit.amount = amount;
+                }
+            }
+
+            // Saves from before power existed have no list, all of their machines run without power
+            let legacy_powered_machines = self.legacy_powered_machines.take();
+
+            for (tile_pos, foreground_object, machine_type, input_items, output_items) in
+                std::mem::take(&mut self.machines)
+            {
+                let legacy_power = legacy_powered_machines
+                    .as_ref()
+                    .is_none_or(|tile_positions| tile_positions.contains(&tile_pos));
+                let tile_pos = translate(&tile_pos);
+
+                self.world_chunks
+                    .get_mut(&chunk_pos(&tile_pos))
+                    .expect("The machines of the old map should be inside of it")
+                    .machines
+                    .push(ChunkMachine {
+                        tile_pos,
+                        foreground_object,
+                        machine: Machine {
+                            legacy_power,
+                            ..Machine::new(machine_type, input_items, output_items)
+                        },
+                    });
+            }
         }
+
+        self.version = GAME_SAVE_VERSION;
     }
 }
 
+/// Saves from before the zoom level was saved start at the default zoom
+fn default_camera_scale() -> f32 {
+    1.0
+}
+
 pub type MachineTiles = Vec<(
     TilePos,
     ForegroundObject,
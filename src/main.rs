@@ -6,11 +6,16 @@ use bevy_ecs_tilemap::prelude::*;
 use bevy_pkv::PkvStore;
 use plugins::{
     building::{BuildingPlugin, Foreground},
+    camera::CameraPlugin,
     crafting::CraftingPlugin,
-    debug_camera::DebugCameraPlugin,
+    drones::DronesPlugin,
     hud::HudPlugin,
+    map::MapPlugin,
     menu::{GameState, MenuPlugin},
+    points::PointsPlugin,
+    power::PowerPlugin,
     rendering::RenderingPlugin,
+    signals::SignalsPlugin,
     simulation::SimulationPlugin,
     world::WorldPlugin,
 };
@@ -99,13 +104,18 @@ fn main() -> AppExit {
             WorldPlugin,
             HudPlugin,
             RenderingPlugin,
-            DebugCameraPlugin,
+            CameraPlugin,
             CraftingPlugin,
             MenuPlugin,
             MachineInteractionPlugin,
             CompletionPlugin,
             AutoSavePlugin,
+            PowerPlugin,
+            PointsPlugin,
+            SignalsPlugin,
+            DronesPlugin,
         ))
+        .add_plugins(MapPlugin)
         .insert_resource(PkvStore::new("com.louisweigel", "sandy-factry"))
         .init_resource::<MouseCoordinates>()
         .insert_resource(ClearColor(Color::hsl(194.0, 0.71, 0.37)))
@@ -6,8 +6,10 @@ use crate::{
     content::machine_types::Machine,
     plugins::{
         auto_save::{AutoSaveTimer, SaveIndicator, SaveIndicatorTimer},
-        completion::HasCompletedGame,
-        world::Seed,
+        camera::{CameraBookmarks, camera_scale},
+        completion::{GameStatistics, HasCompletedGame},
+        points::SinkStatistics,
+        world::{Seed, WorldChunks, generation::WorldGenerationConfig},
     },
     save_game::save_game,
 };
@@ -18,8 +20,13 @@ pub fn check_auto_save(
     mut pkv: ResMut<PkvStore>,
     seed: Res<Seed>,
     tile_query: Query<(&TilePos, &TileTextureIndex, &Machine)>,
-    camera: Single<&Transform, With<Camera2d>>,
+    camera: Single<(&Transform, &Projection), With<Camera2d>>,
+    camera_bookmarks: Res<CameraBookmarks>,
     has_completed_game: Res<HasCompletedGame>,
+    sink_statistics: Res<SinkStatistics>,
+    world_chunks: Res<WorldChunks>,
+    world_generation_config: Res<WorldGenerationConfig>,
+    game_statistics: Res<GameStatistics>,
     mut save_indicator_visibility: Single<&mut Visibility, With<SaveIndicator>>,
     mut save_indicator_timer: ResMut<SaveIndicatorTimer>,
 ) {
@@ -30,8 +37,14 @@ pub fn check_auto_save(
             &mut pkv,
             &seed,
             tile_query.iter().collect(),
-            camera.into_inner().translation,
+            camera.0.translation,
+            camera_scale(camera.1),
+            &camera_bookmarks,
             **has_completed_game,
+            &sink_statistics,
+            &world_chunks,
+            &world_generation_config,
+            &game_statistics,
         );
 
         **save_indicator_visibility = Visibility::Visible;
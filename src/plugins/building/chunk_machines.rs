@@ -0,0 +1,108 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+
+use crate::{
+    content::machine_types::Machine,
+    plugins::{
+        building::{
+            BuildEvent, BuildingInput, BuildingOutput, Foreground,
+            foreground_objects::ForegroundObject,
+            multi_tile::{get_footprint, spawn_machine_parts},
+        },
+        world::{ChunkEvent, ChunkMachine, WorldChunks, generation::chunk_tile_positions},
+    },
+};
+
+/// Move the machines of unloaded chunks into their chunk and spawn the ones of loaded chunks
+///
+/// Unloaded machines don't take part in the simulation, so their chunk stays the way it was left.
+pub fn update_chunk_machines(
+    mut commands: Commands,
+    mut chunk_events: EventReader<ChunkEvent>,
+    mut world_chunks: ResMut<WorldChunks>,
+    foreground_tilemap: Single<(Entity, &mut TileStorage), With<Foreground>>,
+    machine_query: Query<(&TileTextureIndex, &Machine)>,
+    mut event_writer: EventWriter<BuildEvent>,
+) {
+    let (tilemap_entity, mut tile_storage) = foreground_tilemap.into_inner();
+
+    for chunk_event in chunk_events.read() {
+        match *chunk_event {
+            ChunkEvent::Unloaded(chunk_pos) => {
+                let chunk = world_chunks
+                    .get_mut(&chunk_pos)
+                    .expect("Unloaded chunks should have been generated");
+
+                for tile_pos in chunk_tile_positions(chunk_pos) {
+                    let Some((tile_texture_index, machine)) = tile_storage
+                        .get(&tile_pos)
+                        .and_then(|tile_entity| machine_query.get(tile_entity).ok())
+                    else {
+                        continue;
+                    };
+
+                    let foreground_object = ForegroundObject::from(*tile_texture_index);
+
+                    chunk.machines.push(ChunkMachine {
+                        tile_pos,
+                        foreground_object,
+                        machine: machine.clone(),
+                    });
+
+                    // Remove every tile of the machine, even the ones reaching into other chunks
+                    let footprint = get_footprint(tile_pos, foreground_object)
+                        .expect("The machine should fit into the map");
+
+                    for (_, footprint_tile_pos) in footprint {
+                        if let Some(tile_entity) = tile_storage.get(&footprint_tile_pos) {
+                            commands.entity(tile_entity).despawn();
+                            tile_storage.remove(&footprint_tile_pos);
+                        }
+                    }
+                }
+            }
+            ChunkEvent::Loaded(chunk_pos) => {
+                let chunk = world_chunks
+                    .get_mut(&chunk_pos)
+                    .expect("Loaded chunks should have been generated");
+
+                for ChunkMachine {
+                    tile_pos,
+                    foreground_object,
+                    machine,
+                } in std::mem::take(&mut chunk.machines)
+                {
+                    let new_tile_entity = commands
+                        .spawn((
+                            TileBundle {
+                                position: tile_pos,
+                                tilemap_id: TilemapId(tilemap_entity),
+                                texture_index: foreground_object
+                                    .try_into()
+                                    .expect("`Nothing` tile found in chunk!"),
+                                ..Default::default()
+                            },
+                            Foreground,
+                            machine,
+                            BuildingInput(foreground_object.get_input_sides()),
+                            BuildingOutput(foreground_object.get_output_sides()),
+                        ))
+                        .id();
+
+                    event_writer.write(BuildEvent::Placed(tile_pos, foreground_object));
+
+                    commands.entity(tilemap_entity).add_child(new_tile_entity);
+                    tile_storage.set(&tile_pos, new_tile_entity);
+
+                    spawn_machine_parts(
+                        &mut commands,
+                        tilemap_entity,
+                        &mut tile_storage,
+                        tile_pos,
+                        foreground_object,
+                    );
+                }
+            }
+        }
+    }
+}
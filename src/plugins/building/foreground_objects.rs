@@ -1,8 +1,21 @@
 use crate::content::{
     machine_types::Side,
     machines::{
-        belt::Belt, chest::Chest, combiner::Combiner, crafter::Crafter, furnace::Furnace,
-        miner::Miner, splitter::Splitter,
+        belt::{Belt, BeltTier, TieredBelt},
+        chest::Chest,
+        combiner::Combiner,
+        crafter::Crafter,
+        drone_station::DroneStation,
+        furnace::Furnace,
+        generator::Generator,
+        inserter::Inserter,
+        launch_pad::LaunchPad,
+        miner::{Miner, MinerTier},
+        power_pole::PowerPole,
+        signal_pole::SignalPole,
+        sink::Sink,
+        solar_panel::SolarPanel,
+        splitter::Splitter,
     },
 };
 
@@ -111,23 +124,138 @@ pub enum ForegroundObject {
     #[variant(inputs(West, North), outputs(East), texture = 49, machine = Furnace::new(Side::West, Side::North))]
     FurnaceLeftUp,
 
-    #[variant(inputs(North), outputs(South), texture = 50, machine = Belt, render = true, tunnel = Input)]
+    #[variant(inputs(North), outputs(South), texture = 50, machine = Belt, render = true, tunnel = Input, range = 5)]
     TunnelInDown,
-    #[variant(inputs(East), outputs(West), texture = 51, machine = Belt, render = true, tunnel = Input)]
+    #[variant(inputs(East), outputs(West), texture = 51, machine = Belt, render = true, tunnel = Input, range = 5)]
     TunnelInLeft,
-    #[variant(inputs(South), outputs(North), texture = 52, machine = Belt, render = true, tunnel = Input)]
+    #[variant(inputs(South), outputs(North), texture = 52, machine = Belt, render = true, tunnel = Input, range = 5)]
     TunnelInUp,
-    #[variant(inputs(West), outputs(East), texture = 53, machine = Belt, render = true, tunnel = Input)]
+    #[variant(inputs(West), outputs(East), texture = 53, machine = Belt, render = true, tunnel = Input, range = 5)]
     TunnelInRight,
 
-    #[variant(inputs(North), outputs(South), texture = 54, machine = Belt, render = true, tunnel = Output)]
+    #[variant(inputs(North), outputs(South), texture = 54, machine = Belt, render = true, tunnel = Output, range = 5)]
     TunnelOutDown,
-    #[variant(inputs(East), outputs(West), texture = 55, machine = Belt, render = true, tunnel = Output)]
+    #[variant(inputs(East), outputs(West), texture = 55, machine = Belt, render = true, tunnel = Output, range = 5)]
     TunnelOutLeft,
-    #[variant(inputs(South), outputs(North), texture = 56, machine = Belt, render = true, tunnel = Output)]
+    #[variant(inputs(South), outputs(North), texture = 56, machine = Belt, render = true, tunnel = Output, range = 5)]
     TunnelOutUp,
-    #[variant(inputs(West), outputs(East), texture = 57, machine = Belt, render = true, tunnel = Output)]
+    #[variant(inputs(West), outputs(East), texture = 57, machine = Belt, render = true, tunnel = Output, range = 5)]
     TunnelOutRight,
+
+    #[variant(texture = 12, machine = PowerPole)]
+    PowerPole,
+    #[variant(inputs(North, East, South, West), texture = 58, machine = Generator::new())]
+    Generator,
+    #[variant(texture = 59, machine = SolarPanel)]
+    SolarPanel,
+
+    #[variant(texture = 117, machine = SignalPole)]
+    SignalPole,
+
+    #[variant(inputs(North, East, South, West), outputs(North, East, South, West), texture = 118, machine = DroneStation::new())]
+    DroneStation,
+
+    #[variant(inputs(North), outputs(South), texture = 60, machine = Inserter::new())]
+    InserterDown,
+    #[variant(inputs(East), outputs(West), texture = 61, machine = Inserter::new())]
+    InserterLeft,
+    #[variant(inputs(South), outputs(North), texture = 62, machine = Inserter::new())]
+    InserterUp,
+    #[variant(inputs(West), outputs(East), texture = 63, machine = Inserter::new())]
+    InserterRight,
+
+    #[variant(inputs(North, East, South, West), texture = 64, machine = Sink::new())]
+    Sink,
+    #[variant(inputs(North, East, South, West), texture = 119, machine = LaunchPad::new())]
+    LaunchPad,
+
+    #[variant(inputs(South), outputs(North), texture = 65, machine = TieredBelt::new(BeltTier::Express), render = true)]
+    ExpressBeltUp,
+    #[variant(inputs(North), outputs(South), texture = 66, machine = TieredBelt::new(BeltTier::Express), render = true)]
+    ExpressBeltDown,
+    #[variant(inputs(West), outputs(East), texture = 67, machine = TieredBelt::new(BeltTier::Express), render = true)]
+    ExpressBeltRight,
+    #[variant(inputs(East), outputs(West), texture = 68, machine = TieredBelt::new(BeltTier::Express), render = true)]
+    ExpressBeltLeft,
+
+    #[variant(inputs(South), outputs(East), texture = 69, machine = TieredBelt::new(BeltTier::Express), render = true)]
+    ExpressBeltDownRight,
+    #[variant(inputs(West), outputs(South), texture = 70, machine = TieredBelt::new(BeltTier::Express), render = true)]
+    ExpressBeltLeftDown,
+    #[variant(inputs(North), outputs(West), texture = 71, machine = TieredBelt::new(BeltTier::Express), render = true)]
+    ExpressBeltUpLeft,
+    #[variant(inputs(East), outputs(North), texture = 72, machine = TieredBelt::new(BeltTier::Express), render = true)]
+    ExpressBeltRightUp,
+    #[variant(inputs(East), outputs(South), texture = 73, machine = TieredBelt::new(BeltTier::Express), render = true)]
+    ExpressBeltRightDown,
+    #[variant(inputs(South), outputs(West), texture = 74, machine = TieredBelt::new(BeltTier::Express), render = true)]
+    ExpressBeltDownLeft,
+    #[variant(inputs(West), outputs(North), texture = 75, machine = TieredBelt::new(BeltTier::Express), render = true)]
+    ExpressBeltLeftUp,
+    #[variant(inputs(North), outputs(East), texture = 76, machine = TieredBelt::new(BeltTier::Express), render = true)]
+    ExpressBeltUpRight,
+
+    #[variant(inputs(South), outputs(North), texture = 77, machine = TieredBelt::new(BeltTier::Turbo), render = true)]
+    TurboBeltUp,
+    #[variant(inputs(North), outputs(South), texture = 78, machine = TieredBelt::new(BeltTier::Turbo), render = true)]
+    TurboBeltDown,
+    #[variant(inputs(West), outputs(East), texture = 79, machine = TieredBelt::new(BeltTier::Turbo), render = true)]
+    TurboBeltRight,
+    #[variant(inputs(East), outputs(West), texture = 80, machine = TieredBelt::new(BeltTier::Turbo), render = true)]
+    TurboBeltLeft,
+
+    #[variant(inputs(South), outputs(East), texture = 81, machine = TieredBelt::new(BeltTier::Turbo), render = true)]
+    TurboBeltDownRight,
+    #[variant(inputs(West), outputs(South), texture = 82, machine = TieredBelt::new(BeltTier::Turbo), render = true)]
+    TurboBeltLeftDown,
+    #[variant(inputs(North), outputs(West), texture = 83, machine = TieredBelt::new(BeltTier::Turbo), render = true)]
+    TurboBeltUpLeft,
+    #[variant(inputs(East), outputs(North), texture = 84, machine = TieredBelt::new(BeltTier::Turbo), render = true)]
+    TurboBeltRightUp,
+    #[variant(inputs(East), outputs(South), texture = 85, machine = TieredBelt::new(BeltTier::Turbo), render = true)]
+    TurboBeltRightDown,
+    #[variant(inputs(South), outputs(West), texture = 86, machine = TieredBelt::new(BeltTier::Turbo), render = true)]
+    TurboBeltDownLeft,
+    #[variant(inputs(West), outputs(North), texture = 87, machine = TieredBelt::new(BeltTier::Turbo), render = true)]
+    TurboBeltLeftUp,
+    #[variant(inputs(North), outputs(East), texture = 88, machine = TieredBelt::new(BeltTier::Turbo), render = true)]
+    TurboBeltUpRight,
+
+    #[variant(inputs(North), outputs(South), texture = 89, machine = Belt, render = true, tunnel = Input, range = 10)]
+    LongTunnelInDown,
+    #[variant(inputs(East), outputs(West), texture = 90, machine = Belt, render = true, tunnel = Input, range = 10)]
+    LongTunnelInLeft,
+    #[variant(inputs(South), outputs(North), texture = 91, machine = Belt, render = true, tunnel = Input, range = 10)]
+    LongTunnelInUp,
+    #[variant(inputs(West), outputs(East), texture = 92, machine = Belt, render = true, tunnel = Input, range = 10)]
+    LongTunnelInRight,
+
+    #[variant(inputs(North), outputs(South), texture = 93, machine = Belt, render = true, tunnel = Output, range = 10)]
+    LongTunnelOutDown,
+    #[variant(inputs(East), outputs(West), texture = 94, machine = Belt, render = true, tunnel = Output, range = 10)]
+    LongTunnelOutLeft,
+    #[variant(inputs(South), outputs(North), texture = 95, machine = Belt, render = true, tunnel = Output, range = 10)]
+    LongTunnelOutUp,
+    #[variant(inputs(West), outputs(East), texture = 96, machine = Belt, render = true, tunnel = Output, range = 10)]
+    LongTunnelOutRight,
+
+    #[variant(outputs(South), texture = 97, machine = Miner::with_tier(MinerTier::Advanced))]
+    AdvancedMinerDown,
+    #[variant(outputs(West), texture = 98, machine = Miner::with_tier(MinerTier::Advanced))]
+    AdvancedMinerLeft,
+    #[variant(outputs(North), texture = 99, machine = Miner::with_tier(MinerTier::Advanced))]
+    AdvancedMinerUp,
+    #[variant(outputs(East), texture = 100, machine = Miner::with_tier(MinerTier::Advanced))]
+    AdvancedMinerRight,
+
+    #[variant(size = 2, inputs(North(0), North(1)), outputs(South(0), South(1)), texture = 101, machine = Crafter::new())]
+    AssemblerDown,
+    #[variant(size = 2, inputs(East(0), East(1)), outputs(West(0), West(1)), texture = 105, machine = Crafter::new())]
+    AssemblerLeft,
+    #[variant(size = 2, inputs(South(0), South(1)), outputs(North(0), North(1)), texture = 109, machine = Crafter::new())]
+    AssemblerUp,
+    #[variant(size = 2, inputs(West(0), West(1)), outputs(East(0), East(1)), texture = 113, machine = Crafter::new())]
+    AssemblerRight,
 }
 
 impl ForegroundObject {
@@ -242,6 +370,111 @@ impl ForegroundObject {
                 true,
             ),
             (Self::Chest, vec![Self::Chest], false),
+            (Self::PowerPole, vec![Self::PowerPole], false),
+            (Self::Generator, vec![Self::Generator], false),
+            (Self::SolarPanel, vec![Self::SolarPanel], false),
+            (Self::SignalPole, vec![Self::SignalPole], false),
+            (Self::DroneStation, vec![Self::DroneStation], false),
+            (
+                Self::InserterUp,
+                vec![
+                    Self::InserterDown,
+                    Self::InserterLeft,
+                    Self::InserterUp,
+                    Self::InserterRight,
+                ],
+                true,
+            ),
+            (Self::Sink, vec![Self::Sink], false),
+            (Self::LaunchPad, vec![Self::LaunchPad], false),
+            (
+                Self::ExpressBeltUp,
+                vec![
+                    Self::ExpressBeltDown,
+                    Self::ExpressBeltLeft,
+                    Self::ExpressBeltUp,
+                    Self::ExpressBeltRight,
+                ],
+                true,
+            ),
+            (
+                Self::ExpressBeltDownRight,
+                vec![
+                    Self::ExpressBeltDownRight,
+                    Self::ExpressBeltLeftDown,
+                    Self::ExpressBeltUpLeft,
+                    Self::ExpressBeltRightUp,
+                    Self::ExpressBeltRightDown,
+                    Self::ExpressBeltDownLeft,
+                    Self::ExpressBeltLeftUp,
+                    Self::ExpressBeltUpRight,
+                ],
+                false,
+            ),
+            (
+                Self::TurboBeltUp,
+                vec![
+                    Self::TurboBeltDown,
+                    Self::TurboBeltLeft,
+                    Self::TurboBeltUp,
+                    Self::TurboBeltRight,
+                ],
+                true,
+            ),
+            (
+                Self::TurboBeltDownRight,
+                vec![
+                    Self::TurboBeltDownRight,
+                    Self::TurboBeltLeftDown,
+                    Self::TurboBeltUpLeft,
+                    Self::TurboBeltRightUp,
+                    Self::TurboBeltRightDown,
+                    Self::TurboBeltDownLeft,
+                    Self::TurboBeltLeftUp,
+                    Self::TurboBeltUpRight,
+                ],
+                false,
+            ),
+            (
+                Self::LongTunnelInUp,
+                vec![
+                    Self::LongTunnelInDown,
+                    Self::LongTunnelInLeft,
+                    Self::LongTunnelInUp,
+                    Self::LongTunnelInRight,
+                ],
+                true,
+            ),
+            (
+                Self::LongTunnelOutUp,
+                vec![
+                    Self::LongTunnelOutDown,
+                    Self::LongTunnelOutLeft,
+                    Self::LongTunnelOutUp,
+                    Self::LongTunnelOutRight,
+                ],
+                true,
+            ),
+            (
+                Self::AdvancedMinerDown,
+                vec![
+                    Self::AdvancedMinerDown,
+                    Self::AdvancedMinerLeft,
+                    Self::AdvancedMinerUp,
+                    Self::AdvancedMinerRight,
+                ],
+                true,
+            ),
+            (
+                Self::AssemblerDown,
+                vec![
+                    Self::AssemblerDown,
+                    Self::AssemblerLeft,
+                    Self::AssemblerUp,
+                    Self::AssemblerRight,
+                ],
+                true,
+            ),
         ]
     }
 }
@@ -1,9 +1,10 @@
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
+use chunk_machines::update_chunk_machines;
 use foreground_objects::CurrentMachine;
-use load_game_save::load_game_save;
 use place_buildings::place_buildings;
 use serde::{Deserialize, Serialize};
+use tunnels::{draw_tunnel_preview, draw_unpaired_tunnel_warnings};
 
 use crate::{
     Direction,
@@ -11,13 +12,15 @@ use crate::{
         RenderLayer,
         interaction::can_interact_with_world,
         menu::{GameState, game_menus::GameMenuState},
-        world::{MAP_SIZE, MAP_TYPE, TILE_SIZE},
+        world::{MAP_SIZE, MAP_TYPE, TILE_SIZE, chunks::update_loaded_chunks},
     },
 };
 
+mod chunk_machines;
 pub mod foreground_objects;
-mod load_game_save;
+pub mod multi_tile;
 mod place_buildings;
+pub mod tunnels;
 
 // MARK: Plugin
 
@@ -26,10 +29,23 @@ pub struct BuildingPlugin;
 impl Plugin for BuildingPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<BuildEvent>()
-            .add_systems(OnEnter(GameState::Game), (setup, load_game_save).chain())
+            .add_systems(OnEnter(GameState::Game), setup)
             .add_systems(
                 Update,
-                (select_building, place_buildings).run_if(can_interact_with_world),
+                (
+                    select_building,
+                    place_buildings.after(update_chunk_machines),
+                    draw_tunnel_preview,
+                )
+                    .run_if(can_interact_with_world),
+            )
+            .add_systems(
+                Update,
+                (
+                    update_chunk_machines.after(update_loaded_chunks),
+                    draw_unpaired_tunnel_warnings,
+                )
+                    .run_if(in_state(GameState::Game)),
             )
             .add_systems(OnExit(GameMenuState::Hidden), deselect_current_building)
             .add_systems(OnExit(GameState::Game), cleanup);
@@ -51,7 +67,13 @@ pub enum BuildEvent {
 pub struct Foreground;
 
 #[derive(Component)]
-struct HoverBuilding;
+pub struct HoverBuilding;
+
+/// Marks a tile covered by a machine bigger than one tile, that isn't the machine's south west tile
+///
+/// Contains the position of the machine's south west tile, which holds the [Machine](crate::content::machine_types::Machine).
+#[derive(Component, Clone, Copy, Deref)]
+pub struct MachinePart(pub TilePos);
 
 #[derive(Component, Serialize, Deserialize, Deref, DerefMut)]
 pub struct BuildingInput(pub Option<Vec<Direction>>);
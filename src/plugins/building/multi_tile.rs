@@ -0,0 +1,78 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+
+use crate::plugins::world::MAP_SIZE;
+
+use super::{Foreground, MachinePart, foreground_objects::ForegroundObject};
+
+/// Get the positions of all the tiles the machine `foreground_object` covers when placed at `origin`
+///
+/// `origin` is the machine's south west tile. The tiles are returned with their offset from it.
+/// Returns `None` if the machine doesn't fit into the map.
+pub fn get_footprint(
+    origin: TilePos,
+    foreground_object: ForegroundObject,
+) -> Option<Vec<(UVec2, TilePos)>> {
+    let size = foreground_object.size();
+    let mut footprint = Vec::with_capacity((size * size) as usize);
+
+    for y in 0..size {
+        for x in 0..size {
+            let offset = UVec2::new(x, y);
+            let tile_pos = UVec2::from(origin) + offset;
+
+            if tile_pos.x >= MAP_SIZE.x || tile_pos.y >= MAP_SIZE.y {
+                return None;
+            }
+
+            footprint.push((offset, tile_pos.into()));
+        }
+    }
+
+    Some(footprint)
+}
+
+/// Spawn the tiles of the machine `foreground_object` at `origin` apart from the south west one
+///
+/// The machine has to fit into the map.
+pub fn spawn_machine_parts(
+    commands: &mut Commands,
+    tilemap_entity: Entity,
+    tile_storage: &mut TileStorage,
+    origin: TilePos,
+    foreground_object: ForegroundObject,
+) {
+    let footprint =
+        get_footprint(origin, foreground_object).expect("The machine should fit into the map");
+
+    for (offset, tile_pos) in footprint.into_iter().skip(1) {
+        let part_entity = commands
+            .spawn((
+                TileBundle {
+                    position: tile_pos,
+                    tilemap_id: TilemapId(tilemap_entity),
+                    texture_index: foreground_object.texture_index_at(offset),
+                    ..Default::default()
+                },
+                Foreground,
+                MachinePart(origin),
+            ))
+            .id();
+
+        commands.entity(tilemap_entity).add_child(part_entity);
+        tile_storage.set(&tile_pos, part_entity);
+    }
+}
+
+/// Get the south west tile of the machine covering `tile_pos`
+///
+/// Returns `tile_pos` itself if it isn't covered by a part of a bigger machine.
+pub fn get_machine_origin(
+    tile_pos: TilePos,
+    machine_parts: &Query<(&TilePos, &MachinePart)>,
+) -> TilePos {
+    machine_parts
+        .iter()
+        .find(|&(&part_tile_pos, _)| part_tile_pos == tile_pos)
+        .map_or(tile_pos, |(_, machine_part)| **machine_part)
+}
@@ -3,11 +3,15 @@ use bevy::window::PrimaryWindow;
 use bevy_ecs_tilemap::prelude::*;
 use sandy_factry_helpers::tilemap::{TilemapSettingsBorrowed, get_mouse_tilepos, remove_tile};
 
-use crate::content::machine_types::Machine;
+use crate::{
+    content::machine_types::Machine,
+    plugins::world::{LoadedChunks, generation::WorldGenerator},
+};
 
 use super::{
-    BuildEvent, BuildingInput, BuildingOutput, Foreground, HoverBuilding,
+    BuildEvent, BuildingInput, BuildingOutput, Foreground, HoverBuilding, MachinePart,
     foreground_objects::CurrentMachine,
+    multi_tile::{get_footprint, spawn_machine_parts},
 };
 
 /// Place buildings and add hover
@@ -30,10 +34,18 @@ pub fn place_buildings(
         With<Foreground>,
     >,
     tile_query: Query<
-        (Entity, &TilePos, Option<&HoverBuilding>, &TileTextureIndex),
+        (
+            Entity,
+            &TilePos,
+            Option<&HoverBuilding>,
+            &TileTextureIndex,
+            Option<&MachinePart>,
+        ),
         With<Foreground>,
     >,
     current_machine: Res<CurrentMachine>,
+    loaded_chunks: Res<LoadedChunks>,
+    world_generator: Res<WorldGenerator>,
     mut event_writer: EventWriter<BuildEvent>,
 ) {
     // Extract all queried components
@@ -63,12 +75,13 @@ pub fn place_buildings(
         return;
     };
 
-    let mut is_other_tile_at_mouse = false;
+    // The south west tile of the building at the mouse's position
+    let mut building_at_mouse = None;
 
     // Remove hover building
-    for (tile_entity, tile_pos, hover, _) in tile_query.iter() {
+    for (tile_entity, tile_pos, hover, _, machine_part) in tile_query.iter() {
         if *tile_pos == mouse_tile_pos && hover.is_none() {
-            is_other_tile_at_mouse = true;
+            building_at_mouse = Some(machine_part.map_or(*tile_pos, |machine_part| **machine_part));
         } else if hover.is_some() {
             remove_tile(&mut commands, &mut tile_storage, tile_entity, tile_pos);
         }
@@ -77,12 +90,24 @@ pub fn place_buildings(
     if buttons.pressed(MouseButton::Right) {
         // MARK: erasing mode
 
-        for (tile_entity, tile_pos, hover, texture_index) in tile_query.iter() {
-            if *tile_pos == mouse_tile_pos && hover.is_none() {
+        let Some(building_origin) = building_at_mouse else {
+            return;
+        };
+
+        // Remove all the tiles of the building
+        for (tile_entity, tile_pos, hover, texture_index, machine_part) in tile_query.iter() {
+            if hover.is_some() {
+                continue;
+            }
+
+            if *tile_pos == building_origin {
                 commands.entity(tile_entity).despawn();
-                tile_storage.remove(&mouse_tile_pos);
+                tile_storage.remove(tile_pos);
 
                 event_writer.write(BuildEvent::Deleted(*tile_pos, (*texture_index).into()));
+            } else if machine_part.is_some_and(|machine_part| **machine_part == building_origin) {
+                commands.entity(tile_entity).despawn();
+                tile_storage.remove(tile_pos);
             }
         }
 
@@ -98,12 +123,35 @@ pub fn place_buildings(
         .try_into()
         .unwrap_or_else(|_| panic!("This machine shouldn't be selectable: {foreground_object:?}"));
 
-    // Don't try to build if there is already a building at the mouse's position
-    if is_other_tile_at_mouse {
+    // Don't try to build if the building doesn't fit into the map
+    let Some(footprint) = get_footprint(mouse_tile_pos, foreground_object) else {
+        return;
+    };
+
+    // Don't try to build next to unloaded chunks, their machines could be in the way
+    if !footprint
+        .iter()
+        .all(|(_, tile_pos)| loaded_chunks.can_build_on(tile_pos))
+    {
+        return;
+    }
+
+    // Don't try to build if there is already a building on any of the tiles
+    if tile_query.iter().any(|(_, tile_pos, hover, _, _)| {
+        hover.is_none()
+            && footprint
+                .iter()
+                .any(|&(_, footprint_tile_pos)| footprint_tile_pos == *tile_pos)
+    }) {
         return;
     }
 
-    if buttons.pressed(MouseButton::Left) {
+    // Buildings can't be placed on water or rock, but are still shown in red while hovering
+    let is_on_buildable_terrain = footprint
+        .iter()
+        .all(|&(_, tile_pos)| world_generator.background(tile_pos).is_buildable());
+
+    if buttons.pressed(MouseButton::Left) && is_on_buildable_terrain {
         // MARK: building mode
         // Place the current building
 
@@ -136,25 +184,41 @@ pub fn place_buildings(
 
         commands.entity(tilemap_entity).add_child(new_tile_entity);
         tile_storage.set(&mouse_tile_pos, new_tile_entity);
+
+        spawn_machine_parts(
+            &mut commands,
+            tilemap_entity,
+            &mut tile_storage,
+            mouse_tile_pos,
+            foreground_object,
+        );
     } else {
         // MARK: hover mode
-        // Add the hover building
-
-        let new_tile_entity = commands
-            .spawn((
-                TileBundle {
-                    position: mouse_tile_pos,
-                    tilemap_id: TilemapId(tilemap_entity),
-                    texture_index: tile_texture_index,
-                    color: TileColor(Color::srgba(1.0, 1.0, 1.0, 0.7)),
-                    ..Default::default()
-                },
-                Foreground,
-                HoverBuilding,
-            ))
-            .id();
+        // Add the hover building on all the tiles it would cover
+
+        let color = if is_on_buildable_terrain {
+            Color::srgba(1.0, 1.0, 1.0, 0.7)
+        } else {
+            Color::srgba(1.0, 0.3, 0.3, 0.7)
+        };
+
+        for (offset, tile_pos) in footprint {
+            let new_tile_entity = commands
+                .spawn((
+                    TileBundle {
+                        position: tile_pos,
+                        tilemap_id: TilemapId(tilemap_entity),
+                        texture_index: foreground_object.texture_index_at(offset),
+                        color: TileColor(color),
+                        ..Default::default()
+                    },
+                    Foreground,
+                    HoverBuilding,
+                ))
+                .id();
 
-        commands.entity(tilemap_entity).add_child(new_tile_entity);
-        tile_storage.set(&mouse_tile_pos, new_tile_entity);
+            commands.entity(tilemap_entity).add_child(new_tile_entity);
+            tile_storage.set(&tile_pos, new_tile_entity);
+        }
     }
 }
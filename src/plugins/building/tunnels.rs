@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+
+use bevy::{
+    color::palettes::tailwind::{GREEN_400, RED_500, YELLOW_300},
+    prelude::*,
+};
+use bevy_ecs_tilemap::prelude::*;
+use itertools::Itertools;
+
+use crate::{
+    MouseCoordinates,
+    content::machine_types::{Machine, TunnelType},
+    plugins::world::{TILE_SIZE, tile_center},
+};
+
+use super::{
+    Foreground,
+    foreground_objects::{CurrentMachine, ForegroundObject},
+};
+
+/// Find the tunnel output the tunnel input `tunnel_input` at `tile_pos` connects to
+///
+/// Only outputs of the same tier facing the same direction in range of the input are connected.
+/// Returns the position of the nearest one.
+pub fn find_tunnel_output(
+    tile_pos: TilePos,
+    tunnel_input: ForegroundObject,
+    foreground_objects: &HashMap<TilePos, ForegroundObject>,
+) -> Option<TilePos> {
+    let output_side = *tunnel_input
+        .get_output_sides()
+        .expect("All tunnels should have an output")
+        .iter()
+        .exactly_one()
+        .expect("Tunnels have exactly one output");
+
+    let range = tunnel_input
+        .tunnel_range()
+        .expect("All tunnels should have a range");
+
+    // convert the TilePos to a UVec2 for easier calculation
+    let tile_pos_vec = UVec2::from(tile_pos);
+
+    // Try all the different possible tunnel distances
+    // This starts at one to avoid confusion when placing two tunnels right after each other
+    (1..=range).find_map(|i| {
+        let searched_tile_pos = tile_pos_vec
+            .saturating_add_signed(output_side.as_ivec2() * i as i32)
+            .into();
+
+        let foreground_object = foreground_objects.get(&searched_tile_pos)?;
+
+        (matches!(foreground_object.tunnel_type(), Some(TunnelType::Output))
+            && foreground_object.tunnel_range() == Some(range)
+            && foreground_object.get_input_sides() == Some(vec![output_side.get_opposite()]))
+        .then_some(searched_tile_pos)
+    })
+}
+
+/// Collect all placed buildings by their position
+fn placed_foreground_objects(
+    machine_tiles: &Query<(&TilePos, &TileTextureIndex), (With<Foreground>, With<Machine>)>,
+) -> HashMap<TilePos, ForegroundObject> {
+    machine_tiles
+        .iter()
+        .map(|(&tile_pos, &tile_texture_index)| (tile_pos, tile_texture_index.into()))
+        .collect()
+}
+
+/// Show the range of a tunnel input that is about to be placed
+/// and highlight the tunnel output it would connect to
+pub fn draw_tunnel_preview(
+    mouse_coordinates: Res<MouseCoordinates>,
+    machine_tiles: Query<(&TilePos, &TileTextureIndex), (With<Foreground>, With<Machine>)>,
+    current_machine: Res<CurrentMachine>,
+    mut gizmos: Gizmos,
+) {
+    let Some(foreground_object) = current_machine.get_current_foreground_object() else {
+        return;
+    };
+
+    if !matches!(foreground_object.tunnel_type(), Some(TunnelType::Input)) {
+        return;
+    }
+
+    let mouse_tile_pos = mouse_coordinates.as_tile_pos();
+
+    let output_side = *foreground_object
+        .get_output_sides()
+        .expect("All tunnels should have an output")
+        .iter()
+        .exactly_one()
+        .expect("Tunnels have exactly one output");
+
+    let range = foreground_object
+        .tunnel_range()
+        .expect("All tunnels should have a range");
+
+    // Outline all the tiles the tunnel can reach
+    let direction = output_side.as_ivec2().as_vec2();
+    let range_center =
+        tile_center(mouse_tile_pos) + direction * (range as f32 + 1.0) / 2.0 * TILE_SIZE.x;
+    let range_size = (direction.abs() * range as f32 + Vec2::ONE - direction.abs()) * TILE_SIZE.x;
+
+    gizmos.rect_2d(range_center, range_size, YELLOW_300);
+
+    // Highlight the matching tunnel output
+    let foreground_objects = placed_foreground_objects(&machine_tiles);
+
+    if let Some(tunnel_output_pos) =
+        find_tunnel_output(mouse_tile_pos, foreground_object, &foreground_objects)
+    {
+        gizmos.rect_2d(
+            tile_center(tunnel_output_pos),
+            Vec2::splat(TILE_SIZE.x),
+            GREEN_400,
+        );
+    }
+}
+
+/// Mark every tunnel input that has no tunnel output to connect to
+pub fn draw_unpaired_tunnel_warnings(
+    machine_tiles: Query<(&TilePos, &TileTextureIndex), (With<Foreground>, With<Machine>)>,
+    mut gizmos: Gizmos,
+) {
+    let foreground_objects = placed_foreground_objects(&machine_tiles);
+
+    for (&tile_pos, &foreground_object) in &foreground_objects {
+        if matches!(foreground_object.tunnel_type(), Some(TunnelType::Input))
+            && find_tunnel_output(tile_pos, foreground_object, &foreground_objects).is_none()
+        {
+            let center = tile_center(tile_pos);
+            let half_size = TILE_SIZE.x / 2.0;
+
+            gizmos.rect_2d(center, Vec2::splat(TILE_SIZE.x), RED_500);
+            gizmos.line_2d(
+                center - Vec2::splat(half_size),
+                center + Vec2::splat(half_size),
+                RED_500,
+            );
+        }
+    }
+}
@@ -0,0 +1,225 @@
+use bevy::{
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+};
+
+use crate::plugins::{
+    camera::{
+        BOOKMARK_KEYS, BookmarkNamePrompt, BookmarkNameText, BookmarksText, CameraBookmark,
+        CameraBookmarks, MAX_BOOKMARK_NAME_LENGTH, NamedBookmark, camera_scale,
+    },
+    menu::game_menus::GameMenuState,
+};
+
+pub fn setup(mut commands: Commands) {
+    // Spawn the list of bookmarks in the top right corner, below the hovered item
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 15.0,
+            ..default()
+        },
+        TextLayout::new_with_justify(JustifyText::Right),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(60.0),
+            right: Val::Px(5.0),
+            ..default()
+        },
+        BookmarksText,
+    ));
+}
+
+/// Save the current view with Ctrl and a bookmark key and let the player name it
+pub fn save_bookmark(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    camera: Single<(&Transform, &Projection), With<Camera2d>>,
+    mut camera_bookmarks: ResMut<CameraBookmarks>,
+    mut game_menu_state: ResMut<NextState<GameMenuState>>,
+) {
+    if !keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        return;
+    }
+
+    let Some(index) = BOOKMARK_KEYS.iter().position(|key| keys.just_pressed(*key)) else {
+        return;
+    };
+
+    let (camera_transform, projection) = camera.into_inner();
+
+    if camera_bookmarks.len() <= index {
+        camera_bookmarks.resize(index + 1, None);
+    }
+
+    // Keep the name when a bookmark is moved
+    let name = camera_bookmarks[index]
+        .take()
+        .map_or_else(String::new, |bookmark| bookmark.name);
+
+    camera_bookmarks[index] = Some(CameraBookmark {
+        name,
+        translation: camera_transform.translation,
+        scale: camera_scale(projection),
+    });
+
+    commands.insert_resource(NamedBookmark(index));
+    game_menu_state.set(GameMenuState::NameBookmark);
+}
+
+/// Jump to a bookmark with its key, or remove it while holding Shift
+pub fn jump_to_bookmark(
+    keys: Res<ButtonInput<KeyCode>>,
+    camera: Single<(&mut Transform, &mut Projection), With<Camera2d>>,
+    mut camera_bookmarks: ResMut<CameraBookmarks>,
+) {
+    if keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        return;
+    }
+
+    let Some(index) = BOOKMARK_KEYS.iter().position(|key| keys.just_pressed(*key)) else {
+        return;
+    };
+
+    if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        if let Some(bookmark) = camera_bookmarks.get_mut(index) {
+            *bookmark = None;
+        }
+
+        return;
+    }
+
+    let Some(Some(bookmark)) = camera_bookmarks.get(index) else {
+        return;
+    };
+
+    let (mut camera_transform, mut projection) = camera.into_inner();
+
+    camera_transform.translation = bookmark.translation;
+
+    if let Projection::Orthographic(projection) = &mut *projection {
+        projection.scale = bookmark.scale;
+    }
+}
+
+/// Show the names of the bookmarks and their keys
+pub fn update_bookmarks_text(
+    camera_bookmarks: Res<CameraBookmarks>,
+    mut bookmarks_text: Single<&mut Text, With<BookmarksText>>,
+) {
+    if !camera_bookmarks.is_changed() {
+        return;
+    }
+
+    bookmarks_text.0 = camera_bookmarks
+        .iter()
+        .zip(BOOKMARK_KEYS)
+        .filter_map(|(bookmark, key)| {
+            let bookmark = bookmark.as_ref()?;
+            Some(format!("{key:?}: {}", bookmark.name))
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+}
+
+pub fn setup_name_prompt(mut commands: Commands, named_bookmark: Res<NamedBookmark>) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(60.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BookmarkNamePrompt,
+        children![(
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(10.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+            children![
+                Text::new(format!(
+                    "Name the bookmark on {:?} and press Enter",
+                    BOOKMARK_KEYS[**named_bookmark]
+                )),
+                (Text::new(""), BookmarkNameText),
+            ],
+        )],
+    ));
+}
+
+/// Let the player type in the name of the bookmark
+pub fn name_bookmark(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    named_bookmark: Res<NamedBookmark>,
+    mut camera_bookmarks: ResMut<CameraBookmarks>,
+    mut game_menu_state: ResMut<NextState<GameMenuState>>,
+) {
+    let Some(Some(bookmark)) = camera_bookmarks.get_mut(**named_bookmark) else {
+        return;
+    };
+
+    for keyboard_event in keyboard_events.read() {
+        if !keyboard_event.state.is_pressed() {
+            continue;
+        }
+
+        match &keyboard_event.logical_key {
+            Key::Enter => game_menu_state.set(GameMenuState::Hidden),
+            Key::Backspace => {
+                bookmark.name.pop();
+            }
+            Key::Space if bookmark.name.len() < MAX_BOOKMARK_NAME_LENGTH => {
+                bookmark.name.push(' ');
+            }
+            Key::Character(character)
+                if bookmark.name.len() + character.len() <= MAX_BOOKMARK_NAME_LENGTH =>
+            {
+                bookmark.name.push_str(character);
+            }
+            _ => {}
+        }
+    }
+}
+
+pub fn update_bookmark_name_text(
+    named_bookmark: Res<NamedBookmark>,
+    camera_bookmarks: Res<CameraBookmarks>,
+    mut bookmark_name_text: Single<&mut Text, With<BookmarkNameText>>,
+) {
+    if !camera_bookmarks.is_changed() {
+        return;
+    }
+
+    if let Some(Some(bookmark)) = camera_bookmarks.get(**named_bookmark) {
+        bookmark_name_text.0 = format!("{}_", bookmark.name);
+    }
+}
+
+pub fn cleanup_name_prompt(
+    mut commands: Commands,
+    named_bookmark: Res<NamedBookmark>,
+    mut camera_bookmarks: ResMut<CameraBookmarks>,
+    prompt: Single<Entity, With<BookmarkNamePrompt>>,
+) {
+    commands.entity(prompt.entity()).despawn();
+    commands.remove_resource::<NamedBookmark>();
+
+    // Bookmarks always need a name to be shown in the list
+    if let Some(Some(bookmark)) = camera_bookmarks.get_mut(**named_bookmark)
+        && bookmark.name.trim().is_empty()
+    {
+        bookmark.name = default_name(**named_bookmark);
+    }
+}
+
+pub fn cleanup(mut commands: Commands, bookmarks_text: Single<Entity, With<BookmarksText>>) {
+    commands.entity(bookmarks_text.entity()).despawn();
+}
+
+fn default_name(index: usize) -> String {
+    format!("Bookmark {}", index + 1)
+}
@@ -0,0 +1,167 @@
+use bevy::prelude::*;
+use bookmarks::{
+    jump_to_bookmark, name_bookmark, save_bookmark, update_bookmark_name_text,
+    update_bookmarks_text,
+};
+use movement::{clamp_camera, drag_camera, edge_scroll, movement};
+use serde::{Deserialize, Serialize};
+use zoom::zoom;
+
+use crate::{
+    game_save_types::LoadedGameSave,
+    plugins::{
+        interaction::{can_interact_with_world, game_not_paused},
+        menu::{GameState, game_menus::GameMenuState},
+    },
+};
+
+mod bookmarks;
+mod movement;
+mod zoom;
+
+// MARK: Constants
+
+/// How fast the camera moves with the keyboard and edge scrolling, in pixels per second at the default zoom
+const CAMERA_SPEED: f32 = 120.0;
+
+/// The closest the camera can zoom in
+const MIN_ZOOM: f32 = 0.1;
+
+/// The furthest the camera can zoom out
+const MAX_ZOOM: f32 = 3.0;
+
+/// How close to the edge of the window the cursor has to be to move the camera
+const EDGE_SCROLL_MARGIN: f32 = 8.0;
+
+/// The keys that jump to the bookmarks, hold Ctrl to save the current view instead
+const BOOKMARK_KEYS: [KeyCode; 8] = [
+    KeyCode::F1,
+    KeyCode::F2,
+    KeyCode::F3,
+    KeyCode::F4,
+    KeyCode::F5,
+    KeyCode::F6,
+    KeyCode::F7,
+    KeyCode::F8,
+];
+
+/// The longest name a bookmark can have
+const MAX_BOOKMARK_NAME_LENGTH: usize = 24;
+
+// MARK: Plugin
+
+pub struct CameraPlugin;
+
+impl Plugin for CameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameState::Game),
+            (startup, bookmarks::setup).chain(),
+        )
+        .add_systems(
+            Update,
+            (zoom, edge_scroll, save_bookmark, jump_to_bookmark).run_if(can_interact_with_world),
+        )
+        // We want the player to still be able to move around when in a menu, but not while typing a name
+        .add_systems(
+            Update,
+            (movement, drag_camera)
+                .run_if(game_not_paused.and(not(in_state(GameMenuState::NameBookmark)))),
+        )
+        .add_systems(
+            Update,
+            (clamp_camera, update_bookmarks_text).run_if(in_state(GameState::Game)),
+        )
+        .add_systems(
+            OnEnter(GameMenuState::NameBookmark),
+            bookmarks::setup_name_prompt,
+        )
+        .add_systems(
+            Update,
+            (name_bookmark, update_bookmark_name_text)
+                .chain()
+                .run_if(in_state(GameMenuState::NameBookmark)),
+        )
+        .add_systems(
+            OnExit(GameMenuState::NameBookmark),
+            bookmarks::cleanup_name_prompt,
+        )
+        .add_systems(OnExit(GameState::Game), (cleanup, bookmarks::cleanup));
+    }
+}
+
+// MARK: Resources
+
+/// A camera position the player saved to quickly come back to it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CameraBookmark {
+    pub name: String,
+    pub translation: Vec3,
+    pub scale: f32,
+}
+
+/// The bookmarks of every bookmark key, saved with the game
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize, Deref, DerefMut)]
+pub struct CameraBookmarks(Vec<Option<CameraBookmark>>);
+
+/// The index of the bookmark that is currently being named
+#[derive(Resource, Deref)]
+struct NamedBookmark(usize);
+
+// MARK: Components
+
+/// The list of bookmarks in the HUD
+#[derive(Component)]
+struct BookmarksText;
+
+/// The prompt shown while naming a bookmark
+#[derive(Component)]
+struct BookmarkNamePrompt;
+
+/// The text showing the typed in bookmark name
+#[derive(Component)]
+struct BookmarkNameText;
+
+// MARK: Systems
+
+fn startup(
+    mut commands: Commands,
+    game_save: Res<LoadedGameSave>,
+    camera: Single<(&mut Transform, &mut Projection), With<Camera2d>>,
+) {
+    let (mut camera_transform, mut projection) = camera.into_inner();
+
+    let Some(game_save) = &**game_save else {
+        commands.init_resource::<CameraBookmarks>();
+        return;
+    };
+
+    camera_transform.translation = game_save.camera_translation;
+
+    if let Projection::Orthographic(projection) = &mut *projection {
+        projection.scale = game_save.camera_scale.clamp(MIN_ZOOM, MAX_ZOOM);
+    }
+
+    commands.insert_resource(game_save.camera_bookmarks.clone());
+}
+
+fn cleanup(mut commands: Commands, camera: Single<(&mut Projection, &mut Transform)>) {
+    let (mut projection, mut camera) = camera.into_inner();
+
+    commands.remove_resource::<CameraBookmarks>();
+
+    let Projection::Orthographic(projection) = &mut *projection else {
+        return;
+    };
+
+    projection.scale = 1.0;
+    camera.translation = Vec3::ZERO;
+}
+
+/// The zoom level of the camera, saved with the game
+pub fn camera_scale(projection: &Projection) -> f32 {
+    match projection {
+        Projection::Orthographic(projection) => projection.scale,
+        _ => 1.0,
+    }
+}
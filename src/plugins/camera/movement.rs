@@ -0,0 +1,113 @@
+use bevy::{input::mouse::AccumulatedMouseMotion, prelude::*, window::PrimaryWindow};
+
+use crate::plugins::{
+    camera::{CAMERA_SPEED, EDGE_SCROLL_MARGIN},
+    world::{MAP_SIZE, TILE_SIZE},
+};
+
+/// Move the camera with WASD
+pub fn movement(
+    camera: Single<(&Projection, &mut Transform), With<Camera2d>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+) {
+    let (projection, mut camera) = camera.into_inner();
+    let Projection::Orthographic(projection) = projection else {
+        return;
+    };
+
+    let mut translation = Vec2::ZERO;
+
+    if keys.pressed(KeyCode::KeyW) {
+        translation.y += 1.0;
+    }
+
+    if keys.pressed(KeyCode::KeyD) {
+        translation.x += 1.0;
+    }
+
+    if keys.pressed(KeyCode::KeyS) {
+        translation.y -= 1.0;
+    }
+
+    if keys.pressed(KeyCode::KeyA) {
+        translation.x -= 1.0;
+    }
+
+    translation =
+        translation.normalize_or_zero() * CAMERA_SPEED * projection.scale * time.delta_secs();
+
+    camera.translation += translation.extend(0.0);
+}
+
+/// Move the camera while the cursor is close to the edge of the window
+pub fn edge_scroll(
+    camera: Single<(&Projection, &mut Transform), With<Camera2d>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    time: Res<Time>,
+) {
+    let (projection, mut camera) = camera.into_inner();
+    let Projection::Orthographic(projection) = projection else {
+        return;
+    };
+
+    if !window.focused {
+        return;
+    }
+
+    let Some(cursor_position) = window.cursor_position() else {
+        return;
+    };
+
+    let mut translation = Vec2::ZERO;
+
+    if cursor_position.x < EDGE_SCROLL_MARGIN {
+        translation.x -= 1.0;
+    } else if cursor_position.x > window.width() - EDGE_SCROLL_MARGIN {
+        translation.x += 1.0;
+    }
+
+    // The cursor position starts at the top of the window
+    if cursor_position.y < EDGE_SCROLL_MARGIN {
+        translation.y += 1.0;
+    } else if cursor_position.y > window.height() - EDGE_SCROLL_MARGIN {
+        translation.y -= 1.0;
+    }
+
+    translation =
+        translation.normalize_or_zero() * CAMERA_SPEED * projection.scale * time.delta_secs();
+
+    camera.translation += translation.extend(0.0);
+}
+
+/// Move the camera by dragging with the middle mouse button
+pub fn drag_camera(
+    camera: Single<(&Projection, &mut Transform), With<Camera2d>>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
+) {
+    let (projection, mut camera) = camera.into_inner();
+    let Projection::Orthographic(projection) = projection else {
+        return;
+    };
+
+    if !buttons.pressed(MouseButton::Middle) {
+        return;
+    }
+
+    // The map moves with the cursor, and the mouse moves down when moved towards the player
+    camera.translation.x -= mouse_motion.delta.x * projection.scale;
+    camera.translation.y += mouse_motion.delta.y * projection.scale;
+}
+
+/// Keep the middle of the view inside of the map
+pub fn clamp_camera(mut camera: Single<&mut Transform, With<Camera2d>>) {
+    let half_map_size =
+        Vec2::new(MAP_SIZE.x as f32, MAP_SIZE.y as f32) * Vec2::from(TILE_SIZE) / 2.0;
+    let translation = camera.translation.xy().clamp(-half_map_size, half_map_size);
+
+    if translation != camera.translation.xy() {
+        camera.translation.x = translation.x;
+        camera.translation.y = translation.y;
+    }
+}
@@ -0,0 +1,58 @@
+use bevy::{
+    input::mouse::{MouseScrollUnit, MouseWheel},
+    prelude::*,
+    window::PrimaryWindow,
+};
+
+use crate::plugins::camera::{MAX_ZOOM, MIN_ZOOM};
+
+/// Zoom with the scroll wheel towards the cursor, the point under the cursor stays where it is
+pub fn zoom(
+    camera: Single<(&mut Projection, &mut Transform), With<Camera2d>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut evr_scroll: EventReader<MouseWheel>,
+    keys: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+) {
+    let (mut projection, mut camera_transform) = camera.into_inner();
+    let Projection::Orthographic(projection) = &mut *projection else {
+        return;
+    };
+
+    let mut zoom_add = 0.0;
+
+    for event in evr_scroll.read() {
+        match event.unit {
+            MouseScrollUnit::Pixel => zoom_add -= event.y * 0.1,
+            MouseScrollUnit::Line => zoom_add -= event.y * 5.0,
+        }
+    }
+
+    let old_scale = projection.scale;
+
+    // Reset zoom when space is pressed
+    let new_scale = if keys.just_pressed(KeyCode::Space) {
+        1.0
+    } else {
+        (old_scale + zoom_add * time.delta_secs()).clamp(MIN_ZOOM, MAX_ZOOM)
+    };
+
+    if new_scale == old_scale {
+        return;
+    }
+
+    projection.scale = new_scale;
+
+    // Move the camera so the point under the cursor stays in place
+    if zoom_add != 0.0
+        && let Some(cursor_position) = window.cursor_position()
+    {
+        // The cursor position starts at the top of the window
+        let cursor_offset = Vec2::new(
+            cursor_position.x - window.width() / 2.0,
+            window.height() / 2.0 - cursor_position.y,
+        );
+
+        camera_transform.translation += (cursor_offset * (old_scale - new_scale)).extend(0.0);
+    }
+}
@@ -1,20 +1,46 @@
 use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
 
 use crate::{
-    content::items::Item,
-    plugins::{completion::HasCompletedGame, menu::game_menus::GameMenuState},
+    content::{items::ItemType, machine_types::Machine, machines::launch_pad::LaunchPad},
+    plugins::{RenderLayer, completion::LaunchAnimation, rendering::ItemAtlas, world::tile_center},
 };
 
-/// Check whether the game has ended
+/// Start the launch animation for every helicopter a launch pad took this tick
 pub fn check_completion(
-    item_tiles: Query<&Item>,
-    mut game_menu_state: ResMut<NextState<GameMenuState>>,
-    mut has_completed_game: ResMut<HasCompletedGame>,
+    mut commands: Commands,
+    mut machines: Query<(&TilePos, &mut Machine)>,
+    item_atlas: Res<ItemAtlas>,
 ) {
-    for item in item_tiles {
-        if item.ends_game() && !**has_completed_game {
-            game_menu_state.set(GameMenuState::Completed);
-            **has_completed_game = true;
+    for (tile_pos, mut machine) in &mut machines {
+        let Some(launch_pad) = machine
+            .machine_type
+            .as_mut()
+            .as_any_mut()
+            .downcast_mut::<LaunchPad>()
+        else {
+            continue;
+        };
+
+        if launch_pad.take_launches() == 0 {
+            continue;
         }
+
+        let position = tile_center(*tile_pos);
+
+        commands.spawn((
+            Sprite::from_atlas_image(
+                item_atlas.image.clone(),
+                TextureAtlas {
+                    layout: item_atlas.layout.clone(),
+                    index: TileTextureIndex::from(ItemType::Helicopter).0 as usize,
+                },
+            ),
+            Transform::from_translation(position.extend(RenderLayer::SelectionMarker.into())),
+            LaunchAnimation(Timer::from_seconds(
+                LaunchAnimation::DURATION,
+                TimerMode::Once,
+            )),
+        ));
     }
 }
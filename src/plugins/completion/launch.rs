@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use crate::plugins::{
+    completion::{HasCompletedGame, LaunchAnimation},
+    menu::game_menus::GameMenuState,
+};
+
+/// Fly the launched helicopters up and show the completed menu once the first one is gone
+pub fn animate_launch(
+    mut commands: Commands,
+    mut launch_animations: Query<(Entity, &mut LaunchAnimation, &mut Transform)>,
+    time: Res<Time>,
+    mut game_menu_state: ResMut<NextState<GameMenuState>>,
+    mut has_completed_game: ResMut<HasCompletedGame>,
+) {
+    for (entity, mut launch_animation, mut transform) in &mut launch_animations {
+        launch_animation.tick(time.delta());
+
+        // Speed up while rising and grow as if coming closer to the camera
+        let progress = launch_animation.fraction();
+        transform.translation.y += LaunchAnimation::SPEED * progress * time.delta_secs();
+        transform.scale = Vec3::splat(1.0 + progress * 2.0);
+
+        if launch_animation.finished() {
+            commands.entity(entity).despawn();
+
+            if !**has_completed_game {
+                game_menu_state.set(GameMenuState::Completed);
+                **has_completed_game = true;
+            }
+        }
+    }
+}
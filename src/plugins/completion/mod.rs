@@ -1,14 +1,25 @@
+use std::collections::HashMap;
+
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
 use crate::{
+    content::items::ItemType,
     game_save_types::LoadedGameSave,
     plugins::{
-        completion::check_completion::check_completion, menu::GameState,
-        simulation::SimulationUpdate,
+        completion::{
+            check_completion::check_completion,
+            launch::animate_launch,
+            statistics::{count_play_time, count_produced_items},
+        },
+        menu::{GameState, game_menus::GameMenuState},
+        simulation::{SimulationSet, SimulationUpdate},
     },
 };
 
 mod check_completion;
+mod launch;
+mod statistics;
 
 // MARK: Plugin
 pub struct CompletionPlugin;
@@ -18,7 +29,17 @@ impl Plugin for CompletionPlugin {
         app.add_systems(OnEnter(GameState::Game), setup)
             .add_systems(
                 SimulationUpdate,
-                check_completion.run_if(in_state(GameState::Game)),
+                (check_completion, count_produced_items)
+                    .in_set(SimulationSet::PostSimulate)
+                    .run_if(in_state(GameState::Game)),
+            )
+            .add_systems(
+                Update,
+                (
+                    animate_launch,
+                    count_play_time.run_if(not(in_state(GameMenuState::Pause))),
+                )
+                    .run_if(in_state(GameState::Game)),
             )
             .add_systems(OnExit(GameState::Game), cleanup);
     }
@@ -29,6 +50,28 @@ impl Plugin for CompletionPlugin {
 #[derive(Resource, Default, Deref, DerefMut)]
 pub struct HasCompletedGame(bool);
 
+/// The statistics of the current run, shown on the completed menu
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GameStatistics {
+    /// The time played in seconds, not counting the pause menu
+    pub play_time: f64,
+    pub produced_items: HashMap<ItemType, u64>,
+}
+
+// MARK: Components
+
+/// A launched helicopter flying away
+#[derive(Component, Deref, DerefMut)]
+pub struct LaunchAnimation(Timer);
+
+impl LaunchAnimation {
+    /// How long the helicopter is visible in seconds
+    const DURATION: f32 = 3.0;
+
+    /// How fast the helicopter rises at the end of the animation in pixels per second
+    const SPEED: f32 = 60.0;
+}
+
 // MARK: Systems
 
 /// Initialize Resources for [CompletionPlugin]
@@ -39,11 +82,23 @@ fn setup(mut commands: Commands, game_save: Res<LoadedGameSave>) {
         None => false,
     };
 
+    // Retrieve the statistics or start from zero
+    let game_statistics = match &**game_save {
+        Some(game_save) => game_save.game_statistics.clone(),
+        None => GameStatistics::default(),
+    };
+
     // Store completed state
     commands.insert_resource(HasCompletedGame(has_completed));
+    commands.insert_resource(game_statistics);
 }
 
 /// Remove the resources for [CompletionPlugin]
-fn cleanup(mut commands: Commands) {
+fn cleanup(mut commands: Commands, launch_animations: Query<Entity, With<LaunchAnimation>>) {
     commands.remove_resource::<HasCompletedGame>();
+    commands.remove_resource::<GameStatistics>();
+
+    for entity in &launch_animations {
+        commands.entity(entity).despawn();
+    }
 }
@@ -0,0 +1,29 @@
+use bevy::prelude::*;
+
+use crate::{content::machine_types::Machine, plugins::completion::GameStatistics};
+
+/// Count the items all machines produced this tick, for the run and for every machine
+pub fn count_produced_items(
+    mut machines: Query<&mut Machine>,
+    mut game_statistics: ResMut<GameStatistics>,
+) {
+    for mut machine in &mut machines {
+        let machine = &mut *machine;
+
+        for (item, count) in machine.machine_type.take_produced_items() {
+            *game_statistics.produced_items.entry(item).or_default() += count as u64;
+            *machine.statistics.produced_items.entry(item).or_default() += count as u64;
+        }
+
+        machine.statistics.ticks += 1;
+
+        if machine.is_working() {
+            machine.statistics.working_ticks += 1;
+        }
+    }
+}
+
+/// Add the time since the last frame to the play time
+pub fn count_play_time(mut game_statistics: ResMut<GameStatistics>, time: Res<Time>) {
+    game_statistics.play_time += time.delta_secs_f64();
+}
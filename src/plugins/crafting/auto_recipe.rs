@@ -0,0 +1,24 @@
+use bevy::prelude::*;
+
+use crate::{
+    content::{machine_types::Machine, machines::crafter::Crafter},
+    plugins::crafting::CrafterRecipes,
+};
+
+/// Let all crafters in auto mode choose their recipe from the items they received
+pub fn select_auto_recipes(
+    mut machine_query: Query<&mut Machine>,
+    crafter_recipes: Res<CrafterRecipes>,
+) {
+    for mut machine in &mut machine_query {
+        let Machine {
+            machine_type,
+            input_items,
+            ..
+        } = &mut *machine;
+
+        if let Some(crafter) = machine_type.as_mut().as_any_mut().downcast_mut::<Crafter>() {
+            crafter.try_auto_select_recipe(input_items, &crafter_recipes);
+        }
+    }
+}
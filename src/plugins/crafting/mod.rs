@@ -1,10 +1,18 @@
 use std::collections::HashMap;
 
+use auto_recipe::select_auto_recipes;
 use bevy::prelude::*;
 use recipe_types::{CrafterRecipe, FurnaceRecipe};
 
-use crate::{content::items::ItemType, plugins::menu::GameState};
+use crate::{
+    content::items::ItemType,
+    plugins::{
+        menu::GameState,
+        simulation::{SimulationSet, SimulationUpdate},
+    },
+};
 
+mod auto_recipe;
 pub mod recipe_types;
 
 // MARK: Plugin
@@ -13,6 +21,10 @@ pub struct CraftingPlugin;
 impl Plugin for CraftingPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(GameState::Game), add_recipes)
+            .add_systems(
+                SimulationUpdate,
+                select_auto_recipes.in_set(SimulationSet::PreSimulate),
+            )
             .add_systems(OnExit(GameState::Game), cleanup);
     }
 }
@@ -26,20 +38,25 @@ pub struct CrafterRecipes(Vec<CrafterRecipe>);
 pub struct FurnaceRecipes(Vec<FurnaceRecipe>);
 
 macro_rules! furnace_recipe {
-    (out: $output:expr; in: $input:expr; time: $time:expr) => {
-        recipe_types::FurnaceRecipe::new(($output, 1), ($input, 1), $time)
+    (out: $output:expr; in: $input:expr; time: $time:expr; energy: $energy:expr) => {
+        recipe_types::FurnaceRecipe::new(($output, 1), ($input, 1), $time, $energy)
     };
 
-    (out: $output:expr, $output_count:expr; in: $input:expr; time: $time:expr) => {
-        recipe_types::FurnaceRecipe::new(($output, $output_count), ($input, 1), $time)
+    (out: $output:expr, $output_count:expr; in: $input:expr; time: $time:expr; energy: $energy:expr) => {
+        recipe_types::FurnaceRecipe::new(($output, $output_count), ($input, 1), $time, $energy)
     };
 
-    (out: $output:expr; in: $input:expr, $input_count:expr; time: $time:expr) => {
-        recipe_types::FurnaceRecipe::new(($output, 1), ($input, $input_count), $time)
+    (out: $output:expr; in: $input:expr, $input_count:expr; time: $time:expr; energy: $energy:expr) => {
+        recipe_types::FurnaceRecipe::new(($output, 1), ($input, $input_count), $time, $energy)
     };
 
-    (out: $output:expr, $output_count:expr; in: $input:expr, $input_count:expr; time: $time:expr) => {
-        recipe_types::FurnaceRecipe::new(($output, $output_count), ($input, $input_count), $time)
+    (out: $output:expr, $output_count:expr; in: $input:expr, $input_count:expr; time: $time:expr; energy: $energy:expr) => {
+        recipe_types::FurnaceRecipe::new(
+            ($output, $output_count),
+            ($input, $input_count),
+            $time,
+            $energy,
+        )
     };
 }
 
@@ -94,6 +111,8 @@ fn add_recipes(mut commands: Commands) {
         crafter_recipe!(out: ItemType::Gear; in: ItemType::IronIngot, 2; time: 5),
         crafter_recipe!(out: ItemType::Wire, 2; in: ItemType::CopperIngot; time: 5),
         crafter_recipe!(out: ItemType::ReinforcedSteel; in: (ItemType::IronIngot, 1), (ItemType::Steel, 2); time: 50),
+        crafter_recipe!(out: ItemType::Silicon; in: (ItemType::QuartzSand, 2), (ItemType::Coal, 1); time: 30),
+        crafter_recipe!(out: ItemType::Plastic; in: ItemType::CrudeOil, 2; time: 30),
 
         // Advanced Components
         crafter_recipe!(out: ItemType::ElectricalCircuit; in: (ItemType::Wire, 3), (ItemType::CopperIngot, 1); time: 50),
@@ -101,6 +120,11 @@ fn add_recipes(mut commands: Commands) {
         crafter_recipe!(out: ItemType::Motor; in: (ItemType::Steel, 2), (ItemType::Gear, 3); time: 100),
         crafter_recipe!(out: ItemType::Battery; in: (ItemType::CopperIngot, 2), (ItemType::Coal, 1); time: 50),
         crafter_recipe!(out: ItemType::Engine; in: (ItemType::Motor, 2), (ItemType::Gear, 2); time: 100),
+
+        // Modules
+        crafter_recipe!(out: ItemType::SpeedModule; in: (ItemType::ElectricalCircuit, 2), (ItemType::Motor, 1); time: 150),
+        crafter_recipe!(out: ItemType::EfficiencyModule; in: (ItemType::ElectricalCircuit, 2), (ItemType::Battery, 1); time: 150),
+        crafter_recipe!(out: ItemType::ProductivityModule; in: (ItemType::MicroProcessor, 2), (ItemType::Gear, 4); time: 200),
         
         // Helicopter Components
         crafter_recipe!(out: ItemType::RotorBlade; in: ItemType::Steel, 3; time: 50),
@@ -120,9 +144,11 @@ fn add_recipes(mut commands: Commands) {
     ]));
 
     commands.insert_resource(FurnaceRecipes(vec![
-        furnace_recipe!(out: ItemType::CopperIngot; in: ItemType::RawCopper; time: 7),
-        furnace_recipe!(out: ItemType::IronIngot; in: ItemType::RawIron; time: 7),
-        furnace_recipe!(out: ItemType::Steel; in: ItemType::IronIngot, 2; time: 25),
+        furnace_recipe!(out: ItemType::CopperIngot; in: ItemType::RawCopper; time: 7; energy: 15),
+        furnace_recipe!(out: ItemType::IronIngot; in: ItemType::RawIron; time: 7; energy: 15),
+        furnace_recipe!(out: ItemType::Steel; in: ItemType::IronIngot, 2; time: 25; energy: 40),
+        furnace_recipe!(out: ItemType::Glass; in: ItemType::QuartzSand, 2; time: 10; energy: 20),
+        furnace_recipe!(out: ItemType::Brick; in: ItemType::Stone; time: 10; energy: 15),
     ]));
 }
 
@@ -68,18 +68,33 @@ pub struct FurnaceRecipe {
     pub ingredient: (ItemType, u16),
     pub output_item: (ItemType, u16),
     pub burn_time: u8,
+
+    /// The amount of fuel burn time used up by smelting this recipe once
+    #[serde(default = "FurnaceRecipe::default_energy")]
+    pub energy: u16,
 }
 
 impl FurnaceRecipe {
     #[allow(unused)]
-    pub fn new(output_item: (ItemType, u16), ingredient: (ItemType, u16), burn_time: u8) -> Self {
+    pub fn new(
+        output_item: (ItemType, u16),
+        ingredient: (ItemType, u16),
+        burn_time: u8,
+        energy: u16,
+    ) -> Self {
         Self {
             ingredient,
             output_item,
             burn_time,
+            energy,
         }
     }
 
+    /// The energy of recipes saved before every recipe defined its own
+    fn default_energy() -> u16 {
+        15
+    }
+
     pub fn try_crafting(
         &self,
         external_ingredients: &HashMap<ItemType, u16>,
@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+use render::draw_drones;
+
+use crate::plugins::menu::GameState;
+
+mod render;
+
+// MARK: Plugin
+
+pub struct DronesPlugin;
+
+impl Plugin for DronesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, draw_drones.run_if(in_state(GameState::Game)));
+    }
+}
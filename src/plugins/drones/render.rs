@@ -0,0 +1,46 @@
+use bevy::{color::palettes::tailwind::YELLOW_400, prelude::*};
+use bevy_ecs_tilemap::prelude::*;
+
+use crate::{
+    content::{machine_types::Machine, machines::drone_station::DroneStation},
+    plugins::{
+        simulation::SimulationGraph,
+        world::{TILE_SIZE, tile_center},
+    },
+};
+
+/// How visible the links between the drone stations are compared to the drones
+const LINK_ALPHA: f32 = 0.25;
+
+/// Draw the links between the drone stations and every drone on its way along one
+pub fn draw_drones(
+    simulation_graph: Res<SimulationGraph>,
+    machine_query: Query<(&Machine, &TilePos)>,
+    mut gizmos: Gizmos,
+) {
+    for (from, to) in simulation_graph.latency_links() {
+        gizmos.line_2d(
+            tile_center(from),
+            tile_center(to),
+            YELLOW_400.with_alpha(LINK_ALPHA),
+        );
+    }
+
+    for (machine, &tile_pos) in &machine_query {
+        let Some(station) = machine
+            .machine_type
+            .as_ref()
+            .as_any()
+            .downcast_ref::<DroneStation>()
+        else {
+            continue;
+        };
+
+        for flight in station.incoming() {
+            let from = tile_center(flight.from);
+            let position = from.lerp(tile_center(tile_pos), flight.progress());
+
+            gizmos.rect_2d(position, Vec2::splat(TILE_SIZE.x / 2.0), YELLOW_400);
+        }
+    }
+}
@@ -1,7 +1,10 @@
 use bevy::prelude::*;
-use bevy_ecs_tilemap::prelude::*;
 
-use crate::{MouseCoordinates, content::items::Item};
+use crate::{
+    MouseCoordinates,
+    content::items::Item,
+    plugins::{rendering::ItemSprite, world::WorldChunks},
+};
 
 // MARK: Components
 
@@ -29,18 +32,33 @@ pub fn setup(mut commands: Commands) {
 pub fn update_hovered_item_text(
     mut hovered_item_text: Single<&mut Text, With<HoveredItemText>>,
     mouse_coords: Res<MouseCoordinates>,
-    item_tiles: Query<(&Item, &TilePos)>,
+    item_sprites: Query<(&Item, &ItemSprite)>,
+    world_chunks: Res<WorldChunks>,
 ) {
+    let mouse_tile_pos = mouse_coords.as_tile_pos();
+
     // Get the item under the current cursor position
-    hovered_item_text.0 = match item_tiles
+    let item = item_sprites
         .iter()
-        .find(|&(_, &tile_pos)| mouse_coords.as_tile_pos() == tile_pos)
-    {
+        .find(|&(_, item_sprite)| mouse_tile_pos == item_sprite.tile_pos)
+        .map(|(item, _)| item);
+
+    // Get the resource under the current cursor position, unless it's mined out
+    let ore_deposit = world_chunks
+        .ore_at(&mouse_tile_pos)
+        .filter(|ore_deposit| ore_deposit.amount > 0);
+
+    hovered_item_text.0 = match (item, ore_deposit) {
         // Set the label to the item name if there is an item under the cursor
-        Some((item, _)) => item.to_string(),
+        (Some(item), _) => item.to_string(),
+
+        // Else show how much ore is left in the resource under the cursor
+        (None, Some(ore_deposit)) => {
+            format!("{}\n{} left", ore_deposit.resource, ore_deposit.amount)
+        }
 
-        // If there is no item under the cursor, just set it to a blank string
-        None => String::new(),
+        // If there is nothing under the cursor, just set it to a blank string
+        (None, None) => String::new(),
     };
 }
 
@@ -0,0 +1,264 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use itertools::Itertools;
+
+use crate::{
+    MouseCoordinates,
+    content::{
+        items::Item,
+        machine_types::{Machine, PowerRole, Side},
+        machines::{crafter::Crafter, furnace::Furnace},
+    },
+    plugins::building::{
+        MachinePart,
+        foreground_objects::{CurrentMachine, ForegroundObject},
+        multi_tile::get_machine_origin,
+    },
+};
+
+/// The words the names of the variants of a machine end with
+const DIRECTION_WORDS: [&str; 4] = ["Up", "Down", "Left", "Right"];
+
+// MARK: Components
+
+/// Added to the machine shown in the inspector
+///
+/// This component exists one or zero times.
+#[derive(Component)]
+pub struct InspectedMachine;
+
+#[derive(Component)]
+pub struct InspectorPanel;
+
+#[derive(Component)]
+pub struct InspectorText;
+
+// MARK: Systems
+
+pub fn setup(mut commands: Commands) {
+    // Spawn the inspector panel on the left, below the points
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(85.0),
+            left: Val::Px(5.0),
+            padding: UiRect::all(Val::Px(8.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.7)),
+        Visibility::Hidden,
+        InspectorPanel,
+        children![(
+            Text::new(""),
+            TextFont {
+                font_size: 15.0,
+                ..default()
+            },
+            InspectorText,
+        )],
+    ));
+}
+
+/// Inspect the machine under the cursor when clicking on it, clicking anywhere else stops inspecting
+///
+/// Nothing is inspected while a building is selected, because clicking places it.
+pub fn inspect_machine(
+    mut commands: Commands,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mouse_coords: Res<MouseCoordinates>,
+    current_machine: Res<CurrentMachine>,
+    machine_tiles: Query<(Entity, &TilePos), With<Machine>>,
+    machine_parts: Query<(&TilePos, &MachinePart)>,
+    inspected_machines: Query<Entity, With<InspectedMachine>>,
+) {
+    if !mouse_buttons.just_pressed(MouseButton::Left)
+        || current_machine.get_current_foreground_object().is_some()
+    {
+        return;
+    }
+
+    for entity in &inspected_machines {
+        commands.entity(entity).remove::<InspectedMachine>();
+    }
+
+    let machine_origin = get_machine_origin(mouse_coords.as_tile_pos(), &machine_parts);
+
+    if let Some((entity, _)) = machine_tiles
+        .iter()
+        .find(|&(_, &tile_pos)| tile_pos == machine_origin)
+    {
+        commands.entity(entity).insert(InspectedMachine);
+    }
+}
+
+/// Show everything about the inspected machine, or hide the panel if there is none
+pub fn update_inspector(
+    inspected_machine: Option<
+        Single<(&Machine, &TilePos, &TileTextureIndex), With<InspectedMachine>>,
+    >,
+    mut inspector_panel: Single<&mut Visibility, With<InspectorPanel>>,
+    mut inspector_text: Single<&mut Text, With<InspectorText>>,
+) {
+    let Some(inspected_machine) = inspected_machine else {
+        **inspector_panel = Visibility::Hidden;
+        return;
+    };
+
+    let (machine, tile_pos, tile_texture_index) = inspected_machine.into_inner();
+
+    **inspector_panel = Visibility::Visible;
+    inspector_text.0 = describe_machine(
+        machine,
+        *tile_pos,
+        ForegroundObject::from(*tile_texture_index),
+    );
+}
+
+pub fn cleanup(mut commands: Commands, inspector_panel: Single<Entity, With<InspectorPanel>>) {
+    commands.entity(inspector_panel.entity()).despawn();
+}
+
+// MARK: Helpers
+
+/// All the lines of the inspector for `machine`
+fn describe_machine(
+    machine: &Machine,
+    tile_pos: TilePos,
+    foreground_object: ForegroundObject,
+) -> String {
+    let (name, variant) = machine_name(foreground_object);
+    let mut lines = vec![name];
+
+    if !variant.is_empty() {
+        lines.push(format!("Variant: {variant}"));
+    }
+
+    lines.push(format!("Position: {}, {}", tile_pos.x, tile_pos.y));
+
+    // Item queues
+
+    for side in [Side::North, Side::East, Side::South, Side::West] {
+        if let Some(items) = machine.input_items.get_side(&side) {
+            lines.push(format!("Input {side:?}: {}", describe_items(items)));
+        }
+    }
+
+    if let Some(output_items) = &machine.output_items {
+        lines.push(format!(
+            "Output: {}",
+            describe_items(output_items.get_items())
+        ));
+    }
+
+    // Crafting
+
+    let machine_type = machine.machine_type.as_ref().as_any();
+
+    if let Some(crafter) = machine_type.downcast_ref::<Crafter>() {
+        let recipe = crafter.current_recipe.as_ref().map_or_else(
+            || String::from("None"),
+            |recipe| recipe.output_item.to_string(),
+        );
+        let mode = if crafter.auto_recipe { " (auto)" } else { "" };
+
+        lines.push(format!("Recipe: {recipe}{mode}"));
+    }
+
+    if let Some(furnace) = machine_type.downcast_ref::<Furnace>() {
+        let recipe = furnace.current_recipe.as_ref().map_or_else(
+            || String::from("None"),
+            |recipe| recipe.output_item.0.to_string(),
+        );
+
+        lines.push(format!("Recipe: {recipe}"));
+        lines.push(format!(
+            "Burn time: {} / {}",
+            furnace.burn_time(),
+            Furnace::MAX_BURN_TIME
+        ));
+    }
+
+    if let Some(progress) = machine.machine_type.progress() {
+        lines.push(format!("Progress: {:.0}%", progress * 100.0));
+    }
+
+    // State
+
+    match machine.machine_type.power_role() {
+        PowerRole::Consumer(_) if machine.legacy_power => {
+            lines.push(String::from("Power: not needed until connected"))
+        }
+        PowerRole::Consumer(_) => {
+            lines.push(format!("Power: {:.0}%", machine.power.satisfaction * 100.0))
+        }
+        PowerRole::Producer(_) => lines.push(format!("Load: {:.0}%", machine.power.load * 100.0)),
+        PowerRole::None | PowerRole::Pole(_) => {}
+    }
+
+    if machine.disabled_by_signal {
+        lines.push(String::from("Stopped by a signal"));
+    }
+
+    // Statistics
+
+    let statistics = &machine.statistics;
+
+    if statistics.ticks > 0 {
+        lines.push(format!(
+            "Working: {:.0}% of the time",
+            statistics.working_ticks as f64 / statistics.ticks as f64 * 100.0
+        ));
+    }
+
+    for (item_type, count) in statistics
+        .produced_items
+        .iter()
+        .sorted_by_key(|&(item_type, _)| item_type.to_string())
+    {
+        lines.push(format!("Produced: {count} {item_type}"));
+    }
+
+    lines.join("\n")
+}
+
+/// Split the name of `foreground_object` into the machine and its direction, like "Advanced Miner" and "Down"
+fn machine_name(foreground_object: ForegroundObject) -> (String, String) {
+    let mut words = Vec::new();
+    let mut word = String::new();
+
+    for character in format!("{foreground_object:?}").chars() {
+        if character.is_uppercase() && !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+
+        word.push(character);
+    }
+
+    words.push(word);
+
+    let direction_start = words
+        .iter()
+        .position(|word| DIRECTION_WORDS.contains(&word.as_str()))
+        .unwrap_or(words.len());
+
+    (
+        words[..direction_start].join(" "),
+        words[direction_start..].join(" "),
+    )
+}
+
+/// List `items` in their order, with items of the same type next to each other grouped like "3 Coal, 1 Gear"
+fn describe_items<'a>(items: impl IntoIterator<Item = &'a Item>) -> String {
+    let groups = items
+        .into_iter()
+        .chunk_by(|item| item.item_type)
+        .into_iter()
+        .map(|(item_type, group)| format!("{} {item_type}", group.count()))
+        .collect::<Vec<String>>();
+
+    if groups.is_empty() {
+        String::from("Empty")
+    } else {
+        groups.join(", ")
+    }
+}
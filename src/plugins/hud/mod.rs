@@ -1,13 +1,23 @@
 use bevy::prelude::*;
 
 use crate::plugins::{
-    hud::{coordinates::update_coordinates, hovered_item::update_hovered_item_text},
+    hud::{
+        coordinates::update_coordinates,
+        hovered_item::update_hovered_item_text,
+        inspector::{inspect_machine, update_inspector},
+        points::update_points_text,
+        power_statistics::update_power_statistics_text,
+    },
+    interaction::can_interact_with_world,
     menu::GameState,
 };
 
 mod coordinates;
 mod hovered_item;
 mod information;
+mod inspector;
+mod points;
+mod power_statistics;
 
 // MARK: Plugin
 pub struct HudPlugin;
@@ -16,18 +26,36 @@ impl Plugin for HudPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             OnEnter(GameState::Game),
-            (coordinates::setup, hovered_item::setup, information::setup),
+            (
+                coordinates::setup,
+                hovered_item::setup,
+                information::setup,
+                inspector::setup,
+                power_statistics::setup,
+                points::setup,
+            ),
         )
         .add_systems(
             Update,
-            (update_coordinates, update_hovered_item_text).run_if(in_state(GameState::Game)),
+            (
+                update_coordinates,
+                update_hovered_item_text,
+                update_power_statistics_text,
+                update_points_text,
+                update_inspector,
+            )
+                .run_if(in_state(GameState::Game)),
         )
+        .add_systems(Update, inspect_machine.run_if(can_interact_with_world))
         .add_systems(
             OnExit(GameState::Game),
             (
                 coordinates::cleanup,
                 hovered_item::cleanup,
                 information::cleanup,
+                inspector::cleanup,
+                power_statistics::cleanup,
+                points::cleanup,
             ),
         );
     }
@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::plugins::points::SinkStatistics;
+
+#[derive(Component)]
+pub struct PointsText;
+
+pub fn setup(mut commands: Commands) {
+    // Spawn text for the points earned with sinks
+    commands.spawn((
+        Text::new("Points: 0"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(55.0),
+            left: Val::Px(5.0),
+            ..default()
+        },
+        PointsText,
+    ));
+}
+
+/// Update the points text
+pub fn update_points_text(
+    mut points_text: Single<&mut Text, With<PointsText>>,
+    sink_statistics: Res<SinkStatistics>,
+) {
+    if !sink_statistics.is_changed() {
+        return;
+    }
+
+    points_text.0 = format!("Points: {}", sink_statistics.points);
+}
+
+pub fn cleanup(mut commands: Commands, points_text: Single<Entity, With<PointsText>>) {
+    commands.entity(points_text.entity()).despawn();
+}
@@ -0,0 +1,45 @@
+use bevy::prelude::*;
+
+use crate::plugins::power::PowerStatistics;
+
+#[derive(Component)]
+pub struct PowerStatisticsText;
+
+pub fn setup(mut commands: Commands) {
+    // Spawn text for the power statistics
+    commands.spawn((
+        Text::new("Power: 0 / 0 kW"),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(30.0),
+            left: Val::Px(5.0),
+            ..default()
+        },
+        PowerStatisticsText,
+    ));
+}
+
+/// Update the power statistics text
+pub fn update_power_statistics_text(
+    mut power_statistics_text: Single<&mut Text, With<PowerStatisticsText>>,
+    power_statistics: Res<PowerStatistics>,
+) {
+    if !power_statistics.is_changed() {
+        return;
+    }
+
+    power_statistics_text.0 = format!(
+        "Power: {} / {} kW ({:.0}%), {} network(s)",
+        power_statistics.consumption,
+        power_statistics.production,
+        power_statistics.satisfaction() * 100.0,
+        power_statistics.networks,
+    );
+}
+
+pub fn cleanup(
+    mut commands: Commands,
+    power_statistics_text: Single<Entity, With<PowerStatisticsText>>,
+) {
+    commands.entity(power_statistics_text.entity()).despawn();
+}
@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+
+use crate::{
+    MouseCoordinates,
+    content::{
+        machine_types::Machine,
+        machines::{crafter::Crafter, furnace::Furnace},
+    },
+    plugins::{
+        building::{MachinePart, multi_tile::get_machine_origin},
+        interaction::{CopiedRecipe, RecipeSetting},
+    },
+};
+
+/// Copy the recipe setting of the machine under the cursor with C
+/// and paste it onto other machines of the same type with V
+pub fn copy_paste_recipe(
+    mouse_coordinates: Res<MouseCoordinates>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut copied_recipe: ResMut<CopiedRecipe>,
+    mut machine_tiles: Query<(&mut Machine, &TilePos)>,
+    machine_parts: Query<(&TilePos, &MachinePart)>,
+) {
+    let should_copy = keys.just_pressed(KeyCode::KeyC);
+
+    // Pasting works while holding V, so it can be dragged over multiple machines
+    let should_paste = keys.pressed(KeyCode::KeyV);
+
+    if !should_copy && !should_paste {
+        return;
+    }
+
+    let cursor_position = get_machine_origin(mouse_coordinates.as_tile_pos(), &machine_parts);
+
+    // Get the machine directly under the cursor
+    let Some((mut machine, _)) = machine_tiles
+        .iter_mut()
+        .find(|(_, tile_pos)| **tile_pos == cursor_position)
+    else {
+        return;
+    };
+
+    if should_copy {
+        let machine_type = machine.machine_type.as_ref().as_any();
+
+        if let Some(crafter) = machine_type.downcast_ref::<Crafter>() {
+            // A crafter in auto mode should pick its own recipe after pasting
+            let recipe = if crafter.auto_recipe {
+                None
+            } else {
+                crafter.current_recipe.clone()
+            };
+
+            **copied_recipe = Some(RecipeSetting::Crafter {
+                recipe,
+                auto_recipe: crafter.auto_recipe,
+            });
+        } else if let Some(furnace) = machine_type.downcast_ref::<Furnace>() {
+            **copied_recipe = Some(RecipeSetting::Furnace(furnace.current_recipe));
+        }
+    } else if let Some(recipe_setting) = &**copied_recipe {
+        let machine_type = machine.machine_type.as_mut().as_any_mut();
+
+        match recipe_setting {
+            RecipeSetting::Crafter {
+                recipe,
+                auto_recipe,
+            } => {
+                if let Some(crafter) = machine_type.downcast_mut::<Crafter>()
+                    && (crafter.current_recipe != *recipe || crafter.auto_recipe != *auto_recipe)
+                {
+                    crafter.current_recipe = recipe.clone();
+                    crafter.auto_recipe = *auto_recipe;
+                }
+            }
+            RecipeSetting::Furnace(recipe) => {
+                if let Some(furnace) = machine_type.downcast_mut::<Furnace>() {
+                    furnace.current_recipe = *recipe;
+                }
+            }
+        }
+    }
+}
@@ -1,13 +1,18 @@
 use bevy::prelude::*;
 
 use crate::plugins::{
-    interaction::selection_marker::{
-        despawn_selection_marker, hide_selection_marker, spawn_selection_marker,
-        update_selection_marker,
+    crafting::recipe_types::{CrafterRecipe, FurnaceRecipe},
+    interaction::{
+        copy_recipe::copy_paste_recipe,
+        selection_marker::{
+            despawn_selection_marker, hide_selection_marker, spawn_selection_marker,
+            update_selection_marker,
+        },
     },
     menu::{GameState, game_menus::GameMenuState},
 };
 
+mod copy_recipe;
 mod selection_marker;
 
 // MARK: Plugin
@@ -16,16 +21,31 @@ pub struct MachineInteractionPlugin;
 impl Plugin for MachineInteractionPlugin {
     fn build(&self, app: &mut App) {
         app //.init_state::<RecipeMenuState>()
-            .add_systems(OnEnter(GameState::Game), spawn_selection_marker)
+            .add_systems(OnEnter(GameState::Game), (setup, spawn_selection_marker))
             .add_systems(
                 Update,
-                (update_selection_marker).run_if(can_interact_with_world),
+                (update_selection_marker, copy_paste_recipe).run_if(can_interact_with_world),
             )
             .add_systems(OnExit(GameMenuState::Hidden), hide_selection_marker)
-            .add_systems(OnExit(GameState::Game), despawn_selection_marker);
+            .add_systems(OnExit(GameState::Game), (cleanup, despawn_selection_marker));
     }
 }
 
+// MARK: Resources
+
+/// The recipe setting copied from a machine, ready to be pasted onto others
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct CopiedRecipe(Option<RecipeSetting>);
+
+#[derive(Debug, Clone)]
+pub enum RecipeSetting {
+    Crafter {
+        recipe: Option<CrafterRecipe>,
+        auto_recipe: bool,
+    },
+    Furnace(Option<FurnaceRecipe>),
+}
+
 // MARK: Components
 
 #[derive(Component)]
@@ -39,6 +59,14 @@ pub struct SelectedMachine;
 
 // MARK: Systems
 
+fn setup(mut commands: Commands) {
+    commands.init_resource::<CopiedRecipe>();
+}
+
+fn cleanup(mut commands: Commands) {
+    commands.remove_resource::<CopiedRecipe>();
+}
+
 /// Condition whether the world can currently be interacted with.
 ///
 /// True if the game is running and no menu is open
@@ -6,6 +6,7 @@ use crate::{
     content::machine_types::Machine,
     plugins::{
         RenderLayer,
+        building::{MachinePart, multi_tile::get_machine_origin},
         interaction::{SelectedMachine, SelectionMarker},
         menu::game_menus::GameMenuState,
         world::{MAP_SIZE, MAP_TYPE, TILE_SIZE},
@@ -18,6 +19,7 @@ pub fn update_selection_marker(
     cursor_position: Res<MouseCoordinates>,
     selection_marker: Single<(&mut Transform, &mut Visibility), With<SelectionMarker>>,
     machine_tiles: Query<(Entity, &Machine, &TilePos)>,
+    machine_parts: Query<(&TilePos, &MachinePart)>,
     mouse_buttons: Res<ButtonInput<MouseButton>>,
     mut game_menu_state: ResMut<NextState<GameMenuState>>,
 ) {
@@ -26,9 +28,10 @@ pub fn update_selection_marker(
         selection_marker.into_inner();
 
     // Get the machine directly under the cursor
+    let machine_origin = get_machine_origin(cursor_position, &machine_parts);
     let machine_under_cursor = machine_tiles
         .iter()
-        .find(|&(_, _, &tile_pos)| tile_pos == cursor_position)
+        .find(|&(_, _, &tile_pos)| tile_pos == machine_origin)
         .map(|(entity, machine, _)| (entity, machine));
 
     match machine_under_cursor {
@@ -54,7 +57,13 @@ pub fn update_selection_marker(
             // If this machine is clicked, add the marker component
             if mouse_buttons.just_pressed(MouseButton::Left) {
                 commands.entity(machine_entity).insert(SelectedMachine);
-                game_menu_state.set(GameMenuState::Recipe);
+
+                // Machines with settings get the settings menu instead of the recipe menu
+                if machine_under_cursor.machine_type.settings().is_empty() {
+                    game_menu_state.set(GameMenuState::Recipe);
+                } else {
+                    game_menu_state.set(GameMenuState::Settings);
+                }
             }
         }
         _ => {
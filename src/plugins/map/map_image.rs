@@ -0,0 +1,152 @@
+use std::collections::HashSet;
+
+use bevy::{
+    asset::RenderAssetUsages,
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use bevy_ecs_tilemap::prelude::*;
+
+use crate::plugins::{
+    building::{BuildEvent, Foreground, HoverBuilding, multi_tile::get_footprint},
+    map::{MACHINE_COLOR, MapImage},
+    world::{
+        CHUNK_SIZE, MAP_SIZE, WorldChunks, chunk_pos,
+        generation::{WorldGenerator, chunk_tile_positions},
+    },
+};
+
+/// How many chunks are redrawn every frame, the whole map is redrawn about once a second
+const CHUNKS_PER_FRAME: usize = 16;
+
+pub fn setup(mut commands: Commands, mut images: ResMut<Assets<Image>>) {
+    let image = Image::new_fill(
+        Extent3d {
+            width: MAP_SIZE.x,
+            height: MAP_SIZE.y,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+
+    commands.insert_resource(MapImage {
+        image: images.add(image),
+        next_chunk: 0,
+    });
+}
+
+/// Redraw the next few chunks of the map and the chunks something was built in right away
+///
+/// The image is only changed if one of its pixels did, because every change uploads the whole image again.
+pub fn update_map_image(
+    mut map_image: ResMut<MapImage>,
+    mut images: ResMut<Assets<Image>>,
+    mut build_events: EventReader<BuildEvent>,
+    world_generator: Res<WorldGenerator>,
+    world_chunks: Res<WorldChunks>,
+    foreground_tile_storage: Single<&TileStorage, With<Foreground>>,
+    hover_buildings: Query<(), With<HoverBuilding>>,
+) {
+    let chunks_x = (MAP_SIZE.x / CHUNK_SIZE) as usize;
+    let chunk_count = chunks_x * (MAP_SIZE.y / CHUNK_SIZE) as usize;
+
+    let mut chunks_to_draw: Vec<IVec2> = (0..CHUNKS_PER_FRAME)
+        .map(|offset| {
+            let index = (map_image.next_chunk + offset) % chunk_count;
+            IVec2::new((index % chunks_x) as i32, (index / chunks_x) as i32)
+        })
+        .collect();
+    map_image.next_chunk = (map_image.next_chunk + CHUNKS_PER_FRAME) % chunk_count;
+
+    for build_event in build_events.read() {
+        let (BuildEvent::Placed(tile_pos, _) | BuildEvent::Deleted(tile_pos, _)) = build_event;
+
+        // Buildings bigger than one tile can reach into the next chunks
+        for offset in [IVec2::ZERO, IVec2::X, IVec2::Y, IVec2::ONE] {
+            chunks_to_draw.push(chunk_pos(tile_pos) + offset);
+        }
+    }
+
+    let image = images
+        .get(&map_image.image)
+        .expect("The map image should exist while in game");
+    let mut changed_pixels = Vec::new();
+
+    for chunk in chunks_to_draw {
+        if chunk.x >= chunks_x as i32 || chunk.y as u32 >= MAP_SIZE.y / CHUNK_SIZE {
+            continue;
+        }
+
+        // The machines of unloaded chunks are kept in them, including the ones reaching into this chunk
+        let unloaded_machine_tiles: HashSet<TilePos> =
+            [IVec2::ZERO, IVec2::X, IVec2::Y, IVec2::ONE]
+                .into_iter()
+                .filter_map(|offset| world_chunks.get(&(chunk - offset)))
+                .flat_map(|generated_chunk| &generated_chunk.machines)
+                .filter_map(|chunk_machine| {
+                    get_footprint(chunk_machine.tile_pos, chunk_machine.foreground_object)
+                })
+                .flatten()
+                .map(|(_, tile_pos)| tile_pos)
+                .collect();
+
+        for tile_pos in chunk_tile_positions(chunk) {
+            let has_machine = unloaded_machine_tiles.contains(&tile_pos)
+                || foreground_tile_storage
+                    .get(&tile_pos)
+                    .is_some_and(|tile_entity| !hover_buildings.contains(tile_entity));
+
+            let color = if has_machine {
+                MACHINE_COLOR
+            } else if let Some(generated_chunk) = world_chunks.get(&chunk) {
+                // Generated chunks show which ore is mined out already
+                match generated_chunk.ores.get(&tile_pos) {
+                    Some(ore_deposit) if ore_deposit.amount > 0 => ore_deposit.resource.map_color(),
+                    _ => world_generator.background(tile_pos).map_color(),
+                }
+            } else {
+                world_generator.map_color(tile_pos)
+            };
+
+            // Images start at the top, the map at the bottom
+            let pixel = UVec2::new(tile_pos.x, MAP_SIZE.y - 1 - tile_pos.y);
+
+            if !shows_color(image, pixel, color) {
+                changed_pixels.push((pixel, color));
+            }
+        }
+    }
+
+    if changed_pixels.is_empty() {
+        return;
+    }
+
+    let image = images
+        .get_mut(&map_image.image)
+        .expect("The map image should exist while in game");
+
+    for (pixel, color) in changed_pixels {
+        image
+            .set_color_at(pixel.x, pixel.y, color)
+            .expect("The tile should be inside of the map image");
+    }
+}
+
+pub fn cleanup(mut commands: Commands) {
+    commands.remove_resource::<MapImage>();
+}
+
+/// Whether `pixel` of `image` already shows `color`, as far as the image can store it
+fn shows_color(image: &Image, pixel: UVec2, color: Color) -> bool {
+    image.get_color_at(pixel.x, pixel.y).is_ok_and(|current| {
+        current
+            .to_srgba()
+            .to_f32_array()
+            .into_iter()
+            .zip(color.to_srgba().to_f32_array())
+            .all(|(current, color)| (current - color).abs() < 1.0 / u8::MAX as f32)
+    })
+}
@@ -0,0 +1,85 @@
+use bevy::prelude::*;
+
+use crate::plugins::{
+    map::{
+        MINIMAP_SIZE, MINIMAP_TILES, MapImage, Minimap, MinimapFrame, MinimapViewport,
+        VIEWPORT_COLOR, place_viewport, visible_tiles,
+    },
+    world::MAP_SIZE,
+};
+
+pub fn setup(mut commands: Commands, map_image: Res<MapImage>) {
+    // Spawn the minimap in the bottom right corner
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(5.0),
+            right: Val::Px(5.0),
+            width: Val::Px(MINIMAP_SIZE),
+            height: Val::Px(MINIMAP_SIZE),
+            border: UiRect::all(Val::Px(2.0)),
+            overflow: Overflow::clip(),
+            ..default()
+        },
+        BorderColor(Color::BLACK),
+        MinimapFrame,
+        children![
+            (
+                ImageNode::new(map_image.image.clone()),
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                Minimap,
+            ),
+            (
+                Node {
+                    position_type: PositionType::Absolute,
+                    border: UiRect::all(Val::Px(1.0)),
+                    ..default()
+                },
+                BorderColor(VIEWPORT_COLOR),
+                MinimapViewport,
+            ),
+        ],
+    ));
+}
+
+/// Show the part of the map around the camera on the minimap
+pub fn update_minimap(
+    camera: Single<(&Transform, &Projection), With<Camera2d>>,
+    mut minimap: Single<&mut ImageNode, With<Minimap>>,
+    mut viewport: Single<&mut Node, With<MinimapViewport>>,
+) {
+    let (camera_transform, projection) = camera.into_inner();
+    let Projection::Orthographic(projection) = projection else {
+        return;
+    };
+
+    let visible_tiles = visible_tiles(camera_transform, projection);
+
+    // Keep the shown part inside of the map
+    let half_size = Vec2::splat(MINIMAP_TILES / 2.0);
+    let map_size = Vec2::new(MAP_SIZE.x as f32, MAP_SIZE.y as f32);
+    let region = Rect::from_center_half_size(
+        visible_tiles
+            .center()
+            .clamp(half_size, map_size - half_size),
+        half_size,
+    );
+
+    // The image starts at the top of the map
+    minimap.rect = Some(Rect::new(
+        region.min.x,
+        map_size.y - region.max.y,
+        region.max.x,
+        map_size.y - region.min.y,
+    ));
+
+    place_viewport(&mut viewport, visible_tiles, region);
+}
+
+pub fn cleanup(mut commands: Commands, minimap_frame: Single<Entity, With<MinimapFrame>>) {
+    commands.entity(minimap_frame.entity()).despawn();
+}
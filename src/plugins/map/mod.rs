@@ -0,0 +1,119 @@
+use bevy::prelude::*;
+use map_image::update_map_image;
+use minimap::update_minimap;
+use world_map::{toggle_world_map, update_world_map};
+
+use crate::plugins::{
+    menu::{GameState, game_menus::GameMenuState},
+    world::{MAP_SIZE, TILE_SIZE},
+};
+
+mod map_image;
+mod minimap;
+mod world_map;
+
+// MARK: Constants
+
+/// How many tiles the minimap shows in every direction
+const MINIMAP_TILES: f32 = 128.0;
+
+/// How many pixels the minimap is across
+const MINIMAP_SIZE: f32 = 200.0;
+
+/// The color of tiles covered by machines
+const MACHINE_COLOR: Color = Color::srgb(0.91, 0.25, 0.44);
+
+/// The color of the rectangle showing the area the camera sees
+const VIEWPORT_COLOR: Color = Color::WHITE;
+
+// MARK: Plugin
+
+pub struct MapPlugin;
+
+impl Plugin for MapPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnEnter(GameState::Game),
+            (map_image::setup, minimap::setup).chain(),
+        )
+        .add_systems(
+            Update,
+            (update_map_image, update_minimap, toggle_world_map).run_if(in_state(GameState::Game)),
+        )
+        .add_systems(OnEnter(GameMenuState::Map), world_map::setup)
+        .add_systems(
+            Update,
+            update_world_map.run_if(in_state(GameMenuState::Map)),
+        )
+        .add_systems(OnExit(GameMenuState::Map), world_map::cleanup)
+        .add_systems(
+            OnExit(GameState::Game),
+            (map_image::cleanup, minimap::cleanup),
+        );
+    }
+}
+
+// MARK: Resources
+
+/// An image of the whole map with one pixel for every tile, shown by the minimap and the world map
+///
+/// It is redrawn a few chunks at a time.
+#[derive(Resource)]
+pub struct MapImage {
+    image: Handle<Image>,
+
+    /// The index of the chunk that is redrawn next
+    next_chunk: usize,
+}
+
+// MARK: Components
+
+/// The image node of the minimap
+#[derive(Component)]
+struct Minimap;
+
+/// The node holding the minimap and the rectangle on top of it
+#[derive(Component)]
+struct MinimapFrame;
+
+/// The rectangle on the minimap showing the area the camera sees
+#[derive(Component)]
+struct MinimapViewport;
+
+#[derive(Component)]
+struct WorldMapScreen;
+
+/// The image node of the world map, can be clicked to move the camera
+#[derive(Component)]
+struct WorldMap;
+
+/// The rectangle on the world map showing the area the camera sees
+#[derive(Component)]
+struct WorldMapViewport;
+
+// MARK: Helpers
+
+/// The position of the south west corner of the map in the world
+fn map_origin() -> Vec2 {
+    -Vec2::new(MAP_SIZE.x as f32, MAP_SIZE.y as f32) * Vec2::from(TILE_SIZE) / 2.0
+}
+
+/// The area the camera sees, in tiles from the south west corner of the map
+fn visible_tiles(camera_transform: &Transform, projection: &OrthographicProjection) -> Rect {
+    let to_tiles = |position: Vec2| {
+        (camera_transform.translation.xy() + position - map_origin()) / Vec2::from(TILE_SIZE)
+    };
+
+    Rect::from_corners(to_tiles(projection.area.min), to_tiles(projection.area.max))
+}
+
+/// Place `viewport` over the part of `region` the camera sees, `region` being the tiles its map shows
+fn place_viewport(viewport: &mut Node, visible_tiles: Rect, region: Rect) {
+    let size = region.size();
+
+    viewport.left = Val::Percent((visible_tiles.min.x - region.min.x) / size.x * 100.0);
+    // UI nodes go from the top down, the map goes from the bottom up
+    viewport.top = Val::Percent((region.max.y - visible_tiles.max.y) / size.y * 100.0);
+    viewport.width = Val::Percent(visible_tiles.width() / size.x * 100.0);
+    viewport.height = Val::Percent(visible_tiles.height() / size.y * 100.0);
+}
@@ -0,0 +1,115 @@
+use bevy::{prelude::*, ui::RelativeCursorPosition};
+
+use crate::plugins::{
+    map::{
+        MapImage, VIEWPORT_COLOR, WorldMap, WorldMapScreen, WorldMapViewport, map_origin,
+        place_viewport, visible_tiles,
+    },
+    menu::game_menus::GameMenuState,
+    world::{MAP_SIZE, TILE_SIZE},
+};
+
+/// Open and close the world map with M
+pub fn toggle_world_map(
+    current_game_menu_state: Res<State<GameMenuState>>,
+    mut game_menu_state: ResMut<NextState<GameMenuState>>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyM) {
+        return;
+    }
+
+    match current_game_menu_state.get() {
+        GameMenuState::Hidden => game_menu_state.set(GameMenuState::Map),
+        GameMenuState::Map => game_menu_state.set(GameMenuState::Hidden),
+        _ => {}
+    }
+}
+
+pub fn setup(mut commands: Commands, map_image: Res<MapImage>) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.8)),
+        WorldMapScreen,
+        children![
+            (
+                Text::new("Click on the map to move there, press M to close it"),
+                Node {
+                    margin: UiRect::all(Val::Px(10.0)),
+                    ..default()
+                },
+            ),
+            (
+                ImageNode::new(map_image.image.clone()),
+                Node {
+                    width: Val::VMin(85.0),
+                    height: Val::VMin(85.0),
+                    border: UiRect::all(Val::Px(2.0)),
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                BorderColor(Color::BLACK),
+                Button,
+                RelativeCursorPosition::default(),
+                WorldMap,
+                children![(
+                    Node {
+                        position_type: PositionType::Absolute,
+                        border: UiRect::all(Val::Px(1.0)),
+                        ..default()
+                    },
+                    BorderColor(VIEWPORT_COLOR),
+                    WorldMapViewport,
+                )],
+            ),
+        ],
+    ));
+}
+
+/// Move the rectangle showing the camera and jump to the clicked position
+pub fn update_world_map(
+    camera: Single<(&mut Transform, &Projection), With<Camera2d>>,
+    world_map: Single<(&Interaction, &RelativeCursorPosition), With<WorldMap>>,
+    mut viewport: Single<&mut Node, With<WorldMapViewport>>,
+    mut game_menu_state: ResMut<NextState<GameMenuState>>,
+) {
+    let (mut camera_transform, projection) = camera.into_inner();
+    let Projection::Orthographic(projection) = projection else {
+        return;
+    };
+
+    let (interaction, relative_cursor_position) = world_map.into_inner();
+
+    // The cursor position goes from -0.5 to 0.5 with the middle of the map at 0
+    if *interaction == Interaction::Pressed
+        && let Some(cursor_position) = relative_cursor_position.normalized
+    {
+        let map_size = Vec2::new(MAP_SIZE.x as f32, MAP_SIZE.y as f32);
+        // UI nodes go from the top down, the map goes from the bottom up
+        let tile = Vec2::new(cursor_position.x + 0.5, 0.5 - cursor_position.y) * map_size;
+        let position = map_origin() + tile * Vec2::from(TILE_SIZE);
+
+        camera_transform.translation.x = position.x;
+        camera_transform.translation.y = position.y;
+
+        game_menu_state.set(GameMenuState::Hidden);
+    }
+
+    let region = Rect::new(0.0, 0.0, MAP_SIZE.x as f32, MAP_SIZE.y as f32);
+    place_viewport(
+        &mut viewport,
+        visible_tiles(&camera_transform, projection),
+        region,
+    );
+}
+
+pub fn cleanup(mut commands: Commands, world_map_screen: Single<Entity, With<WorldMapScreen>>) {
+    commands.entity(world_map_screen.entity()).despawn();
+}
@@ -1,11 +1,17 @@
 use bevy::prelude::*;
 
-use crate::plugins::menu::{
-    MENU_BACKGROUND, NORMAL_BUTTON, TEXT_COLOR,
-    game_menus::completed_menu::{CompletedMenuButtonAction, CompletedMenuScreen},
+use crate::plugins::{
+    completion::GameStatistics,
+    menu::{
+        MENU_BACKGROUND, NORMAL_BUTTON, TEXT_COLOR,
+        game_menus::completed_menu::{CompletedMenuButtonAction, CompletedMenuScreen},
+    },
 };
 
-pub fn setup_completed_menu(mut commands: Commands) {
+/// How many of the most produced items are listed
+const LISTED_ITEMS: usize = 3;
+
+pub fn setup_completed_menu(mut commands: Commands, game_statistics: Res<GameStatistics>) {
     // Common style for all buttons on the screen
     let button_node = Node {
         width: Val::Px(350.0),
@@ -20,6 +26,23 @@ pub fn setup_completed_menu(mut commands: Commands) {
         ..default()
     };
 
+    // Summarize the run
+    let play_time = game_statistics.play_time as u64;
+    let mut produced_items: Vec<_> = game_statistics.produced_items.iter().collect();
+    produced_items.sort_unstable_by(|a, b| b.1.cmp(a.1));
+
+    let mut statistics = format!(
+        "Play Time: {}:{:02}:{:02}\nItems Produced: {}",
+        play_time / 3600,
+        play_time / 60 % 60,
+        play_time % 60,
+        produced_items.iter().map(|(_, count)| **count).sum::<u64>(),
+    );
+
+    for (item, count) in produced_items.into_iter().take(LISTED_ITEMS) {
+        statistics.push_str(&format!("\n{item}: {count}"));
+    }
+
     commands.spawn((
         Node {
             width: Val::Percent(100.0),
@@ -49,6 +72,15 @@ pub fn setup_completed_menu(mut commands: Commands) {
                         ..default()
                     },
                 ),
+                (
+                    Text::new(statistics),
+                    TextFont {
+                        font_size: 25.0,
+                        ..default()
+                    },
+                    TextColor(TEXT_COLOR),
+                    TextLayout::new_with_justify(JustifyText::Center),
+                ),
                 // Display three buttons for each action available from the pause menu:
                 // - continue
                 // - main menu
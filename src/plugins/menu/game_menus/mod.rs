@@ -4,13 +4,15 @@ use crate::plugins::menu::{
     GameState,
     game_menus::{
         completed_menu::CompletedMenuPlugin, pause_menu::PauseMenuPlugin,
-        recipe_menu::RecipeMenuPlugin, show_game_menu::show_game_menu,
+        recipe_menu::RecipeMenuPlugin, settings_menu::SettingsMenuPlugin,
+        show_game_menu::show_game_menu,
     },
 };
 
 mod completed_menu;
 mod pause_menu;
 mod recipe_menu;
+mod settings_menu;
 mod show_game_menu;
 
 // MARK: Plugin
@@ -18,9 +20,14 @@ pub struct GameMenusPlugin;
 
 impl Plugin for GameMenusPlugin {
     fn build(&self, app: &mut App) {
-        app.add_plugins((PauseMenuPlugin, RecipeMenuPlugin, CompletedMenuPlugin))
-            .init_state::<GameMenuState>()
-            .add_systems(Update, show_game_menu.run_if(in_state(GameState::Game)));
+        app.add_plugins((
+            PauseMenuPlugin,
+            RecipeMenuPlugin,
+            SettingsMenuPlugin,
+            CompletedMenuPlugin,
+        ))
+        .init_state::<GameMenuState>()
+        .add_systems(Update, show_game_menu.run_if(in_state(GameState::Game)));
     }
 }
 
@@ -32,5 +39,8 @@ pub enum GameMenuState {
     Hidden,
     Pause,
     Recipe,
+    Settings,
     Completed,
+    Map,
+    NameBookmark,
 }
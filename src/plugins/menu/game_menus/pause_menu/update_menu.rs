@@ -5,7 +5,8 @@ use bevy_pkv::PkvStore;
 use crate::{
     content::machine_types::Machine,
     plugins::{
-        completion::HasCompletedGame,
+        camera::{CameraBookmarks, camera_scale},
+        completion::{GameStatistics, HasCompletedGame},
         menu::{
             GameState,
             game_menus::{
@@ -13,7 +14,8 @@ use crate::{
                 pause_menu::{PauseMenuButtonAction, SaveButtonText},
             },
         },
-        world::Seed,
+        points::SinkStatistics,
+        world::{Seed, WorldChunks, generation::WorldGenerationConfig},
     },
     save_game::save_game,
 };
@@ -29,8 +31,13 @@ pub fn update_game_menu(
     mut pkv: ResMut<PkvStore>,
     seed: Res<Seed>,
     tile_query: Query<(&TilePos, &TileTextureIndex, &Machine)>,
-    camera: Single<&Transform, With<Camera2d>>,
+    camera: Single<(&Transform, &Projection), With<Camera2d>>,
+    camera_bookmarks: Res<CameraBookmarks>,
     has_completed_game: Res<HasCompletedGame>,
+    sink_statistics: Res<SinkStatistics>,
+    world_chunks: Res<WorldChunks>,
+    world_generation_config: Res<WorldGenerationConfig>,
+    game_statistics: Res<GameStatistics>,
     mut save_button_text: Single<&mut Text, With<SaveButtonText>>,
 ) {
     let mut should_save_game = false;
@@ -63,8 +70,14 @@ pub fn update_game_menu(
             &mut pkv,
             &seed,
             tile_query.iter().collect(),
-            camera.into_inner().translation,
+            camera.0.translation,
+            camera_scale(camera.1),
+            &camera_bookmarks,
             **has_completed_game,
+            &sink_statistics,
+            &world_chunks,
+            &world_generation_config,
+            &game_statistics,
         );
     }
 }
@@ -1,5 +1,5 @@
 use bevy::{
-    color::palettes::tailwind::{GRAY_400, GRAY_500},
+    color::palettes::tailwind::{GRAY_400, GRAY_500, GRAY_600, ORANGE_400},
     ecs::spawn::SpawnWith,
     prelude::*,
 };
@@ -13,7 +13,8 @@ use crate::{
         crafting::{CrafterRecipes, FurnaceRecipes, recipe_types::Recipe},
         interaction::SelectedMachine,
         menu::game_menus::recipe_menu::{
-            LINE_HEIGHT, RecipeButton, RecipeDetailText, RecipeScreen,
+            FuelGaugeBar, FuelGaugeText, LINE_HEIGHT, ModuleSlotButton, RecipeButton,
+            RecipeDetailText, RecipeScreen,
         },
     },
 };
@@ -26,12 +27,26 @@ pub fn create_recipe_screen(
 ) {
     let mut recipe_rows = Vec::new();
 
+    // Only furnaces show a fuel gauge
+    let mut has_fuel_gauge = false;
+
+    let module_slot_count = selected_machine
+        .machine_type
+        .modules()
+        .map_or(0, |modules| modules.slots().len());
+
     if let Some(crafter) = selected_machine
         .machine_type
         .as_ref()
         .as_any()
         .downcast_ref::<Crafter>()
     {
+        recipe_rows.push((
+            String::from("Auto"),
+            crafter.auto_recipe,
+            RecipeButton::Auto,
+        ));
+
         for recipe in crafter_recipes.iter() {
             recipe_rows.push((
                 recipe.output_item.to_string(),
@@ -40,7 +55,7 @@ pub fn create_recipe_screen(
                     .clone()
                     .map(|current_recipe| current_recipe == *recipe)
                     .is_some_and(|value| value),
-                RecipeButton(Recipe::Crafter(recipe.clone())),
+                RecipeButton::Recipe(Recipe::Crafter(recipe.clone())),
             ));
         }
     } else if let Some(furnace) = selected_machine
@@ -49,6 +64,8 @@ pub fn create_recipe_screen(
         .as_any()
         .downcast_ref::<Furnace>()
     {
+        has_fuel_gauge = true;
+
         for recipe in furnace_recipes.iter() {
             recipe_rows.push((
                 recipe.output_item.0.to_string(),
@@ -56,7 +73,7 @@ pub fn create_recipe_screen(
                     .current_recipe
                     .map(|current_recipe| current_recipe == *recipe)
                     .is_some_and(|value| value),
-                RecipeButton(Recipe::Furnace(*recipe)),
+                RecipeButton::Recipe(Recipe::Furnace(*recipe)),
             ));
         }
     }
@@ -121,10 +138,72 @@ pub fn create_recipe_screen(
                         width: Val::Percent(50.0),
                         padding: UiRect::all(Val::Px(5.0)),
                         height: Val::Auto,
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::SpaceBetween,
                         ..default()
                     },
                     BackgroundColor(GRAY_400.into()),
-                    children![(Text::new(""), RecipeDetailText)]
+                    Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                        parent.spawn((Text::new(""), RecipeDetailText));
+
+                        if has_fuel_gauge {
+                            parent.spawn((
+                                Node {
+                                    flex_direction: FlexDirection::Column,
+                                    row_gap: Val::Px(5.0),
+                                    ..default()
+                                },
+                                children![
+                                    (Text::new(""), FuelGaugeText),
+                                    (
+                                        Node {
+                                            width: Val::Percent(100.0),
+                                            height: Val::Px(12.0),
+                                            ..default()
+                                        },
+                                        BackgroundColor(GRAY_600.into()),
+                                        children![(
+                                            Node {
+                                                width: Val::Percent(0.0),
+                                                height: Val::Percent(100.0),
+                                                ..default()
+                                            },
+                                            BackgroundColor(ORANGE_400.into()),
+                                            FuelGaugeBar,
+                                        )]
+                                    )
+                                ],
+                            ));
+                        }
+
+                        if module_slot_count > 0 {
+                            parent.spawn((
+                                Node {
+                                    flex_direction: FlexDirection::Column,
+                                    row_gap: Val::Px(5.0),
+                                    ..default()
+                                },
+                                Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                                    parent.spawn(Text::new("Modules (click to remove)"));
+
+                                    for slot in 0..module_slot_count {
+                                        parent.spawn((
+                                            Node {
+                                                height: Val::Px(LINE_HEIGHT),
+                                                padding: UiRect::horizontal(Val::Px(5.0)),
+                                                align_items: AlignItems::Center,
+                                                ..default()
+                                            },
+                                            Text::new(""),
+                                            BackgroundColor(GRAY_500.into()),
+                                            ModuleSlotButton(slot),
+                                            Button,
+                                        ));
+                                    }
+                                })),
+                            ));
+                        }
+                    }))
                 )
             ],
         )],
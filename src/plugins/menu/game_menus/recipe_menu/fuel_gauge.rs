@@ -0,0 +1,36 @@
+use bevy::prelude::*;
+
+use crate::{
+    content::{machine_types::Machine, machines::furnace::Furnace},
+    plugins::{
+        interaction::SelectedMachine,
+        menu::game_menus::recipe_menu::{FuelGaugeBar, FuelGaugeText},
+    },
+};
+
+/// Keep the fuel gauge in sync with the burn time of the selected furnace
+pub fn update_fuel_gauge(
+    selected_machine: Single<&Machine, With<SelectedMachine>>,
+    mut fuel_gauge_bars: Query<&mut Node, With<FuelGaugeBar>>,
+    mut fuel_gauge_texts: Query<&mut Text, With<FuelGaugeText>>,
+) {
+    let Some(furnace) = selected_machine
+        .machine_type
+        .as_ref()
+        .as_any()
+        .downcast_ref::<Furnace>()
+    else {
+        return;
+    };
+
+    let fuel_percentage =
+        (furnace.burn_time() as f32 / Furnace::MAX_BURN_TIME as f32 * 100.0).min(100.0);
+
+    for mut fuel_gauge_bar in &mut fuel_gauge_bars {
+        fuel_gauge_bar.width = Val::Percent(fuel_percentage);
+    }
+
+    for mut fuel_gauge_text in &mut fuel_gauge_texts {
+        fuel_gauge_text.0 = format!("Fuel: {} / {}", furnace.burn_time(), Furnace::MAX_BURN_TIME);
+    }
+}
@@ -15,6 +15,7 @@ use crate::plugins::{
             GameMenuState,
             recipe_menu::{
                 create_recipe_screen::create_recipe_screen, deselect_machine::deselect_machine,
+                fuel_gauge::update_fuel_gauge, module_slots::update_module_slots,
                 update_recipe_screen::update_recipe_screen,
             },
         },
@@ -23,6 +24,8 @@ use crate::plugins::{
 
 pub mod create_recipe_screen;
 pub mod deselect_machine;
+pub mod fuel_gauge;
+pub mod module_slots;
 pub mod update_recipe_screen;
 
 // MARK: Constants
@@ -37,7 +40,12 @@ impl Plugin for RecipeMenuPlugin {
         app.add_systems(OnEnter(GameMenuState::Recipe), create_recipe_screen)
             .add_systems(
                 Update,
-                (update_recipe_screen, update_scroll_position)
+                (
+                    update_recipe_screen,
+                    update_fuel_gauge,
+                    update_module_slots,
+                    update_scroll_position,
+                )
                     .run_if(in_state(GameMenuState::Recipe)),
             )
             .add_systems(
@@ -55,8 +63,24 @@ pub struct RecipeScreen;
 #[derive(Component)]
 pub struct RecipeDetailText;
 
-#[derive(Component, Deref)]
-pub struct RecipeButton(Recipe);
+/// The text showing the exact fuel left in a furnace
+#[derive(Component)]
+pub struct FuelGaugeText;
+
+/// The bar filled according to the fuel left in a furnace
+#[derive(Component)]
+pub struct FuelGaugeBar;
+
+/// A module slot of the selected machine, clicking it removes the module
+#[derive(Component)]
+pub struct ModuleSlotButton(pub usize);
+
+#[derive(Component)]
+pub enum RecipeButton {
+    /// Let the crafter choose its recipe from the items it receives
+    Auto,
+    Recipe(Recipe),
+}
 
 // MARK: States
 
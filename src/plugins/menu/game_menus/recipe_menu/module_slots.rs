@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+use crate::{
+    content::machine_types::Machine,
+    plugins::{interaction::SelectedMachine, menu::game_menus::recipe_menu::ModuleSlotButton},
+};
+
+/// Show the modules of the selected machine and take them out when their slot is clicked
+///
+/// A removed module is put into the machine's output, so it can be used somewhere else.
+pub fn update_module_slots(
+    mut module_slots: Query<(&mut Text, &ModuleSlotButton, Ref<Interaction>)>,
+    mut selected_machine: Single<&mut Machine, With<SelectedMachine>>,
+) {
+    let machine = &mut **selected_machine;
+
+    let Some(modules) = machine.machine_type.modules_mut() else {
+        return;
+    };
+
+    for (mut slot_text, &ModuleSlotButton(slot), interaction) in &mut module_slots {
+        if interaction.is_changed()
+            && *interaction == Interaction::Pressed
+            && let Some(output_items) = machine.output_items.as_mut()
+            && let Some(module) = modules.remove(slot)
+        {
+            output_items.get_items_mut().push_back(module.item().into());
+        }
+
+        slot_text.0 = match modules.slots()[slot] {
+            Some(module) => module.to_string(),
+            None => String::from("Empty"),
+        };
+    }
+}
@@ -28,8 +28,13 @@ pub fn update_recipe_screen(
     for (interaction, recipe_button) in interaction_query {
         match interaction {
             Interaction::Hovered => {
-                match &recipe_button.0 {
-                    Recipe::Crafter(crafter_recipe) => {
+                match recipe_button {
+                    RecipeButton::Auto => {
+                        ***recipe_detail_text = String::from(
+                            "Auto\n\nChooses the recipe matching the items this crafter receives.\nThe recipe stays selected once it was chosen.",
+                        );
+                    }
+                    RecipeButton::Recipe(Recipe::Crafter(crafter_recipe)) => {
                         let mut ingredients_list = String::new();
 
                         for (ingredient, ingredient_count) in &crafter_recipe.ingredients {
@@ -44,22 +49,36 @@ pub fn update_recipe_screen(
                             crafter_recipe.crafting_time,
                         );
                     }
-                    Recipe::Furnace(furnace_recipe) => {
+                    RecipeButton::Recipe(Recipe::Furnace(furnace_recipe)) => {
                         ***recipe_detail_text = format!(
-                            "{}x {}\n\nIngredients:\n- {}x {}\nBurn Time: {} ticks",
+                            "{}x {}\n\nIngredients:\n- {}x {}\nBurn Time: {} ticks\nEnergy: {} fuel",
                             furnace_recipe.output_item.1,
                             furnace_recipe.output_item.0,
                             furnace_recipe.ingredient.1,
                             furnace_recipe.ingredient.0,
                             furnace_recipe.burn_time,
+                            furnace_recipe.energy,
                         );
                     }
                 }
 
                 is_nothing_hovered = false;
             }
-            Interaction::Pressed => match &recipe_button.0 {
-                Recipe::Crafter(crafter_recipe) => {
+            Interaction::Pressed => match recipe_button {
+                RecipeButton::Auto => {
+                    if let Some(crafter) = selected_machine
+                        .1
+                        .machine_type
+                        .as_mut()
+                        .as_any_mut()
+                        .downcast_mut::<Crafter>()
+                    {
+                        crafter.current_recipe = None;
+                        crafter.auto_recipe = true;
+                        is_nothing_pressed = false;
+                    }
+                }
+                RecipeButton::Recipe(Recipe::Crafter(crafter_recipe)) => {
                     if let Some(crafter) = selected_machine
                         .1
                         .machine_type
@@ -68,10 +87,11 @@ pub fn update_recipe_screen(
                         .downcast_mut::<Crafter>()
                     {
                         crafter.current_recipe = Some(crafter_recipe.clone());
+                        crafter.auto_recipe = false;
                         is_nothing_pressed = false;
                     }
                 }
-                Recipe::Furnace(furnace_recipe) => {
+                RecipeButton::Recipe(Recipe::Furnace(furnace_recipe)) => {
                     if let Some(furnace) = selected_machine
                         .1
                         .machine_type
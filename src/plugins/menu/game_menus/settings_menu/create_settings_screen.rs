@@ -0,0 +1,115 @@
+use bevy::{
+    color::palettes::tailwind::{GRAY_400, GRAY_500, GRAY_600},
+    ecs::spawn::SpawnWith,
+    prelude::*,
+};
+
+use crate::{
+    content::machine_types::Machine,
+    plugins::{
+        interaction::SelectedMachine,
+        menu::game_menus::settings_menu::{InventorySlotText, SettingButton, SettingsScreen},
+    },
+};
+
+pub fn create_settings_screen(
+    mut commands: Commands,
+    selected_machine: Single<&Machine, With<SelectedMachine>>,
+) {
+    let settings = selected_machine.machine_type.settings();
+    let inventory_size = selected_machine
+        .machine_type
+        .inventory()
+        .map(|slots| slots.len());
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        children![(
+            Node {
+                width: Val::Px(600.0),
+                height: Val::Px(300.0),
+                padding: UiRect::all(Val::Px(5.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(10.0),
+                overflow: Overflow {
+                    x: OverflowAxis::Hidden,
+                    y: OverflowAxis::Scroll
+                },
+                ..default()
+            },
+            BackgroundColor(GRAY_400.into()),
+            Children::spawn(SpawnWith(move |parent: &mut ChildSpawner| {
+                // Show the contents of machines storing items
+                if let Some(inventory_size) = inventory_size {
+                    parent.spawn(Text::new("Contents"));
+
+                    parent
+                        .spawn(Node {
+                            flex_wrap: FlexWrap::Wrap,
+                            column_gap: Val::Px(5.0),
+                            row_gap: Val::Px(5.0),
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            for slot_index in 0..inventory_size {
+                                parent.spawn((
+                                    Node {
+                                        width: Val::Px(140.0),
+                                        padding: UiRect::all(Val::Px(4.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(GRAY_600.into()),
+                                    Text::new(""),
+                                    InventorySlotText(slot_index),
+                                ));
+                            }
+                        });
+                }
+
+                for (setting_index, setting) in settings.into_iter().enumerate() {
+                    parent.spawn(Text::new(setting.name));
+
+                    parent
+                        .spawn(Node {
+                            flex_wrap: FlexWrap::Wrap,
+                            column_gap: Val::Px(5.0),
+                            row_gap: Val::Px(5.0),
+                            ..default()
+                        })
+                        .with_children(|parent| {
+                            for (option_index, option) in setting.options.into_iter().enumerate() {
+                                parent.spawn((
+                                    Node {
+                                        padding: UiRect::axes(Val::Px(8.0), Val::Px(4.0)),
+                                        ..default()
+                                    },
+                                    Text::new(option),
+                                    Pickable {
+                                        should_block_lower: false,
+                                        ..default()
+                                    },
+                                    if option_index == setting.selected {
+                                        BackgroundColor(GRAY_500.into())
+                                    } else {
+                                        BackgroundColor(GRAY_400.into())
+                                    },
+                                    SettingButton {
+                                        setting: setting_index,
+                                        option: option_index,
+                                    },
+                                    Button,
+                                ));
+                            }
+                        });
+                }
+            }))
+        )],
+        SettingsScreen,
+    ));
+}
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+use crate::{
+    content::machine_types::{ItemSlot, Machine},
+    plugins::{interaction::SelectedMachine, menu::game_menus::settings_menu::InventorySlotText},
+};
+
+/// Show the current contents of every slot of the selected machine
+pub fn update_inventory(
+    mut slot_texts: Query<(&mut Text, &InventorySlotText)>,
+    selected_machine: Single<&Machine, With<SelectedMachine>>,
+) {
+    let Some(slots) = selected_machine.machine_type.inventory() else {
+        return;
+    };
+
+    for (mut slot_text, &InventorySlotText(slot_index)) in &mut slot_texts {
+        let slot = &slots[slot_index];
+
+        slot_text.0 = match slot.item {
+            Some(item) => format!("{item}\n{}/{}", slot.count, ItemSlot::CAPACITY),
+            None => match slot.filter {
+                Some(filter) => format!("Empty\n({filter} only)"),
+                None => String::from("Empty"),
+            },
+        };
+    }
+}
@@ -0,0 +1,55 @@
+use bevy::prelude::*;
+
+use crate::plugins::menu::{
+    despawn_screen,
+    game_menus::{
+        GameMenuState,
+        recipe_menu::{deselect_machine::deselect_machine, update_scroll_position},
+        settings_menu::{
+            create_settings_screen::create_settings_screen, inventory::update_inventory,
+            update_settings_screen::update_settings_screen,
+        },
+    },
+};
+
+pub mod create_settings_screen;
+pub mod inventory;
+pub mod update_settings_screen;
+
+// MARK: Plugin
+pub struct SettingsMenuPlugin;
+
+impl Plugin for SettingsMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameMenuState::Settings), create_settings_screen)
+            .add_systems(
+                Update,
+                (
+                    update_settings_screen,
+                    update_inventory,
+                    update_scroll_position,
+                )
+                    .run_if(in_state(GameMenuState::Settings)),
+            )
+            .add_systems(
+                OnExit(GameMenuState::Settings),
+                (despawn_screen::<SettingsScreen>, deselect_machine),
+            );
+    }
+}
+
+// MARK: Components
+
+#[derive(Component)]
+pub struct SettingsScreen;
+
+/// A button selecting the option at `option` of the setting at `setting`
+#[derive(Component)]
+pub struct SettingButton {
+    pub setting: usize,
+    pub option: usize,
+}
+
+/// The text showing the contents of the inventory slot at this index
+#[derive(Component)]
+pub struct InventorySlotText(pub usize);
@@ -0,0 +1,42 @@
+use bevy::{
+    color::palettes::tailwind::{GRAY_400, GRAY_500},
+    prelude::*,
+};
+
+use crate::{
+    content::machine_types::Machine,
+    plugins::{interaction::SelectedMachine, menu::game_menus::settings_menu::SettingButton},
+};
+
+pub fn update_settings_screen(
+    interaction_query: Query<(&Interaction, &SettingButton), Changed<Interaction>>,
+    mut button_query: Query<(&SettingButton, &mut BackgroundColor)>,
+    mut selected_machine: Single<&mut Machine, With<SelectedMachine>>,
+) {
+    let mut was_changed = false;
+
+    for (interaction, setting_button) in interaction_query {
+        if *interaction == Interaction::Pressed {
+            selected_machine
+                .machine_type
+                .apply_setting(setting_button.setting, setting_button.option);
+
+            was_changed = true;
+        }
+    }
+
+    if !was_changed {
+        return;
+    }
+
+    // Highlight the options that are selected now
+    let settings = selected_machine.machine_type.settings();
+
+    for (setting_button, mut background_color) in &mut button_query {
+        *background_color = if settings[setting_button.setting].selected == setting_button.option {
+            BackgroundColor(GRAY_500.into())
+        } else {
+            BackgroundColor(GRAY_400.into())
+        };
+    }
+}
@@ -4,11 +4,16 @@ use crate::plugins::menu::{
     GameState, button_system, despawn_screen,
     main_menu::{
         how_to_play::{setup_how_to_play_menu, update_how_to_play_menu},
+        new_game::{
+            cleanup_new_game_menu, setup_new_game_menu, type_seed, update_new_game_menu,
+            update_world_preview,
+        },
         start_menu::{setup_main_menu, update_main_menu},
     },
 };
 
 pub mod how_to_play;
+pub mod new_game;
 pub mod start_menu;
 
 // MARK: Plugin
@@ -41,6 +46,16 @@ impl Plugin for MainMenuPlugin {
             .add_systems(
                 OnExit(MainMenuState::HowToPlay),
                 despawn_screen::<HowToPlayMenu>,
+            )
+            .add_systems(OnEnter(MainMenuState::NewGame), setup_new_game_menu)
+            .add_systems(
+                Update,
+                (update_new_game_menu, type_seed, update_world_preview)
+                    .run_if(in_state(MainMenuState::NewGame)),
+            )
+            .add_systems(
+                OnExit(MainMenuState::NewGame),
+                (despawn_screen::<NewGameMenu>, cleanup_new_game_menu),
             );
     }
 }
@@ -53,6 +68,7 @@ pub enum MainMenuState {
     Hidden,
     Menu,
     HowToPlay,
+    NewGame,
 }
 
 // MARK: Components
@@ -62,3 +78,6 @@ struct MainMenuScreen;
 
 #[derive(Component)]
 struct HowToPlayMenu;
+
+#[derive(Component)]
+struct NewGameMenu;
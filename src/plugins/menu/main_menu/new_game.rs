@@ -0,0 +1,499 @@
+use bevy::{
+    asset::RenderAssetUsages,
+    image::ImageSampler,
+    input::keyboard::{Key, KeyboardInput},
+    prelude::*,
+    render::render_resource::{Extent3d, TextureDimension, TextureFormat},
+};
+use bevy_ecs_tilemap::prelude::*;
+use bevy_pkv::PkvStore;
+use strum::IntoEnumIterator;
+
+use crate::{
+    game_save_types::{GameSave, LoadedGameSave},
+    plugins::{
+        menu::{
+            GameState, HOVERED_PRESSED_BUTTON, MAIN_TEXT_COLOR, MENU_BACKGROUND, NORMAL_BUTTON,
+            SelectedOption, TEXT_COLOR,
+            main_menu::{MainMenuState, NewGameMenu},
+        },
+        world::{
+            MAP_SIZE, NewWorldSettings, Seed,
+            generation::{MapSizePreset, ResourcePreset, WorldGenerationConfig, WorldGenerator},
+        },
+    },
+    save_keys::SaveKey,
+};
+
+/// How many pixels the world preview is across, every pixel shows a few tiles
+const PREVIEW_SIZE: u32 = 128;
+
+/// The most digits a seed can have
+const MAX_SEED_LENGTH: usize = 10;
+
+#[derive(Component)]
+pub enum NewGameMenuAction {
+    RandomizeSeed,
+    MapSize(MapSizePreset),
+    Resources(ResourcePreset),
+    Start,
+    Back,
+}
+
+/// The text showing the typed in seed
+#[derive(Component)]
+pub struct SeedText;
+
+/// The image showing a preview of the world
+#[derive(Component)]
+pub struct WorldPreview;
+
+/// The text telling the player why the game didn't start yet
+#[derive(Component)]
+pub struct StartWarning;
+
+/// The options chosen on the new game screen
+#[derive(Resource, Debug, Clone)]
+pub struct NewGameOptions {
+    seed_text: String,
+    map_size: MapSizePreset,
+    resources: ResourcePreset,
+
+    /// Whether Start has to be pressed again, because starting overwrites the existing save
+    confirm_overwrite: bool,
+}
+
+impl NewGameOptions {
+    /// The typed in seed, `None` while no seed is typed in
+    fn seed(&self) -> Option<Seed> {
+        self.seed_text.parse().ok().map(Seed::new)
+    }
+
+    fn config(&self) -> WorldGenerationConfig {
+        WorldGenerationConfig::from_presets(self.map_size, self.resources)
+    }
+}
+
+impl Default for NewGameOptions {
+    fn default() -> Self {
+        Self {
+            seed_text: Seed::random().value().to_string(),
+            map_size: default(),
+            resources: default(),
+            confirm_overwrite: false,
+        }
+    }
+}
+
+pub fn setup_new_game_menu(
+    mut commands: Commands,
+    mut images: ResMut<Assets<Image>>,
+    pkv: Res<PkvStore>,
+) {
+    let options = NewGameOptions {
+        confirm_overwrite: pkv.get::<GameSave>(SaveKey::GameSave).is_ok(),
+        ..default()
+    };
+
+    let button_node = Node {
+        width: Val::Px(300.0),
+        height: Val::Px(65.0),
+        margin: UiRect::all(Val::Px(20.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    let button_text_font = TextFont {
+        font_size: 33.0,
+        ..default()
+    };
+    let option_node = Node {
+        width: Val::Px(120.0),
+        height: Val::Px(40.0),
+        margin: UiRect::all(Val::Px(5.0)),
+        justify_content: JustifyContent::Center,
+        align_items: AlignItems::Center,
+        ..default()
+    };
+    let option_text_font = TextFont {
+        font_size: 20.0,
+        ..default()
+    };
+    let label_node = Node {
+        width: Val::Px(140.0),
+        ..default()
+    };
+    let row_node = Node {
+        flex_direction: FlexDirection::Row,
+        align_items: AlignItems::Center,
+        margin: UiRect::horizontal(Val::Px(20.0)),
+        ..default()
+    };
+
+    // The preview is generated in `update_world_preview` as soon as the options are added
+    let preview = images.add(Image::default());
+
+    let menu = commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            NewGameMenu,
+        ))
+        .id();
+
+    let panel = commands
+        .spawn((
+            Node {
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(MENU_BACKGROUND),
+            ChildOf(menu),
+            children![(
+                Text::new("New Game"),
+                TextFont {
+                    font_size: 67.0,
+                    ..default()
+                },
+                TextColor(MAIN_TEXT_COLOR),
+                Node {
+                    margin: UiRect::all(Val::Px(30.0)),
+                    ..default()
+                },
+            )],
+        ))
+        .id();
+
+    // Type in a seed or roll a random one
+    commands.spawn((
+        row_node.clone(),
+        ChildOf(panel),
+        children![
+            (
+                Text::new("Seed"),
+                option_text_font.clone(),
+                TextColor(MAIN_TEXT_COLOR),
+                label_node.clone(),
+            ),
+            (
+                Text::new(options.seed_text.clone()),
+                option_text_font.clone(),
+                TextColor(TEXT_COLOR),
+                Node {
+                    width: Val::Px(250.0),
+                    padding: UiRect::all(Val::Px(8.0)),
+                    margin: UiRect::all(Val::Px(5.0)),
+                    ..default()
+                },
+                BackgroundColor(NORMAL_BUTTON),
+                SeedText,
+            ),
+            (
+                Button,
+                option_node.clone(),
+                BackgroundColor(NORMAL_BUTTON),
+                NewGameMenuAction::RandomizeSeed,
+                children![(
+                    Text::new("Randomize"),
+                    option_text_font.clone(),
+                    TextColor(TEXT_COLOR),
+                )]
+            ),
+        ],
+    ));
+
+    // The presets
+    let map_size_row = commands
+        .spawn((
+            row_node.clone(),
+            ChildOf(panel),
+            children![(
+                Text::new("Map Size"),
+                option_text_font.clone(),
+                TextColor(MAIN_TEXT_COLOR),
+                label_node.clone(),
+            )],
+        ))
+        .id();
+
+    for map_size in MapSizePreset::iter() {
+        let mut button = commands.spawn((
+            Button,
+            option_node.clone(),
+            BackgroundColor(NORMAL_BUTTON),
+            NewGameMenuAction::MapSize(map_size),
+            ChildOf(map_size_row),
+            children![(
+                Text::new(map_size.to_string()),
+                option_text_font.clone(),
+                TextColor(TEXT_COLOR),
+            )],
+        ));
+
+        if map_size == options.map_size {
+            button.insert((SelectedOption, BackgroundColor(HOVERED_PRESSED_BUTTON)));
+        }
+    }
+
+    let resources_row = commands
+        .spawn((
+            row_node.clone(),
+            ChildOf(panel),
+            children![(
+                Text::new("Resources"),
+                option_text_font.clone(),
+                TextColor(MAIN_TEXT_COLOR),
+                label_node.clone(),
+            )],
+        ))
+        .id();
+
+    for resources in ResourcePreset::iter() {
+        let mut button = commands.spawn((
+            Button,
+            option_node.clone(),
+            BackgroundColor(NORMAL_BUTTON),
+            NewGameMenuAction::Resources(resources),
+            ChildOf(resources_row),
+            children![(
+                Text::new(resources.to_string()),
+                option_text_font.clone(),
+                TextColor(TEXT_COLOR),
+            )],
+        ));
+
+        if resources == options.resources {
+            button.insert((SelectedOption, BackgroundColor(HOVERED_PRESSED_BUTTON)));
+        }
+    }
+
+    // Preview of the world
+    commands.spawn((
+        ImageNode::new(preview),
+        Node {
+            width: Val::Px(256.0),
+            height: Val::Px(256.0),
+            margin: UiRect::all(Val::Px(10.0)),
+            ..default()
+        },
+        WorldPreview,
+        ChildOf(panel),
+    ));
+
+    commands.spawn((
+        Text::new(""),
+        option_text_font.clone(),
+        TextColor(MAIN_TEXT_COLOR),
+        StartWarning,
+        ChildOf(panel),
+    ));
+
+    commands.spawn((
+        row_node,
+        ChildOf(panel),
+        children![
+            (
+                Button,
+                button_node.clone(),
+                BackgroundColor(NORMAL_BUTTON),
+                NewGameMenuAction::Back,
+                children![(
+                    Text::new("Back"),
+                    button_text_font.clone(),
+                    TextColor(TEXT_COLOR),
+                )]
+            ),
+            (
+                Button,
+                button_node,
+                BackgroundColor(NORMAL_BUTTON),
+                NewGameMenuAction::Start,
+                children![(Text::new("Start"), button_text_font, TextColor(TEXT_COLOR),)]
+            ),
+        ],
+    ));
+
+    commands.insert_resource(options);
+}
+
+pub fn update_new_game_menu(
+    mut commands: Commands,
+    interaction_query: Query<
+        (&Interaction, &NewGameMenuAction),
+        (Changed<Interaction>, With<Button>),
+    >,
+    mut option_buttons: Query<(
+        Entity,
+        &NewGameMenuAction,
+        &mut BackgroundColor,
+        Has<SelectedOption>,
+    )>,
+    mut options: ResMut<NewGameOptions>,
+    mut game_state: ResMut<NextState<GameState>>,
+    mut main_menu_state: ResMut<NextState<MainMenuState>>,
+    mut current_game_save: ResMut<LoadedGameSave>,
+    mut start_warning: Single<&mut Text, With<StartWarning>>,
+) {
+    for (interaction, new_game_action) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        match new_game_action {
+            NewGameMenuAction::RandomizeSeed => {
+                options.seed_text = Seed::random().value().to_string();
+            }
+            NewGameMenuAction::MapSize(map_size) => options.map_size = *map_size,
+            NewGameMenuAction::Resources(resources) => options.resources = *resources,
+            NewGameMenuAction::Start => {
+                let Some(seed) = options.seed() else {
+                    start_warning.0 = String::from("Type in a seed or randomize it");
+                    continue;
+                };
+
+                // The save is only overwritten after the player was warned
+                if options.confirm_overwrite {
+                    options.confirm_overwrite = false;
+                    start_warning.0 =
+                        String::from("This overwrites your saved game, press Start again");
+                    continue;
+                }
+
+                commands.insert_resource(NewWorldSettings {
+                    seed,
+                    config: options.config(),
+                });
+
+                // Start without a save, it is overwritten the next time the game is saved
+                **current_game_save = None;
+
+                game_state.set(GameState::Game);
+                main_menu_state.set(MainMenuState::Hidden);
+            }
+            NewGameMenuAction::Back => {
+                main_menu_state.set(MainMenuState::Menu);
+            }
+        }
+    }
+
+    if !options.is_changed() {
+        return;
+    }
+
+    // Only the chosen presets are highlighted
+    for (entity, action, mut background_color, is_selected) in &mut option_buttons {
+        let should_be_selected = match action {
+            NewGameMenuAction::MapSize(map_size) => *map_size == options.map_size,
+            NewGameMenuAction::Resources(resources) => *resources == options.resources,
+            _ => continue,
+        };
+
+        if should_be_selected && !is_selected {
+            commands.entity(entity).insert(SelectedOption);
+            *background_color = HOVERED_PRESSED_BUTTON.into();
+        } else if !should_be_selected && is_selected {
+            commands.entity(entity).remove::<SelectedOption>();
+            *background_color = NORMAL_BUTTON.into();
+        }
+    }
+}
+
+/// Let the player type in a seed
+pub fn type_seed(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut options: ResMut<NewGameOptions>,
+    mut seed_text: Single<&mut Text, With<SeedText>>,
+) {
+    for keyboard_event in keyboard_events.read() {
+        if !keyboard_event.state.is_pressed() {
+            continue;
+        }
+
+        match &keyboard_event.logical_key {
+            Key::Backspace => {
+                options.seed_text.pop();
+            }
+            Key::Character(character) => {
+                let mut new_seed_text = options.seed_text.clone();
+                new_seed_text.push_str(character);
+
+                // Only keep seeds that fit into a number
+                if new_seed_text.len() <= MAX_SEED_LENGTH && new_seed_text.parse::<u32>().is_ok() {
+                    options.seed_text = new_seed_text;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if options.is_changed() {
+        seed_text.0 = options.seed_text.clone();
+    }
+}
+
+/// Generate the preview again if any option changed
+pub fn update_world_preview(
+    options: Res<NewGameOptions>,
+    preview: Single<&ImageNode, With<WorldPreview>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    if !options.is_changed() {
+        return;
+    }
+
+    // Keep the last preview while no seed is typed in
+    let Some(seed) = options.seed() else {
+        return;
+    };
+
+    let Some(image) = images.get_mut(&preview.image) else {
+        return;
+    };
+
+    *image = preview_image(&WorldGenerator::new(seed, options.config()));
+}
+
+/// Remove the options once the new game screen is left
+pub fn cleanup_new_game_menu(mut commands: Commands) {
+    commands.remove_resource::<NewGameOptions>();
+}
+
+/// An image of the whole map with one pixel for every few tiles
+fn preview_image(world_generator: &WorldGenerator) -> Image {
+    let mut image = Image::new_fill(
+        Extent3d {
+            width: PREVIEW_SIZE,
+            height: PREVIEW_SIZE,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        &[0, 0, 0, 255],
+        TextureFormat::Rgba8UnormSrgb,
+        RenderAssetUsages::default(),
+    );
+    image.sampler = ImageSampler::nearest();
+
+    let tiles_per_pixel = MAP_SIZE.x / PREVIEW_SIZE;
+
+    for x in 0..PREVIEW_SIZE {
+        for y in 0..PREVIEW_SIZE {
+            let tile_pos = TilePos {
+                x: x * tiles_per_pixel,
+                y: y * tiles_per_pixel,
+            };
+
+            // Images start at the top, the map at the bottom
+            image
+                .set_color_at(x, PREVIEW_SIZE - 1 - y, world_generator.map_color(tile_pos))
+                .expect("The pixel should be inside of the preview");
+        }
+    }
+
+    image
+}
@@ -13,6 +13,7 @@ use crate::{
 #[derive(Component)]
 pub enum MainMenuButtonAction {
     Play,
+    NewGame,
     Quit,
     HowToPlay,
 }
@@ -69,10 +70,11 @@ pub fn setup_main_menu(
                             ..default()
                         },
                     ),
-                    // Display three buttons for each action available from the main menu:
+                    // Display four buttons for each action available from the main menu:
                     // - play
-                    // - quit
+                    // - new game
                     // - how to play
+                    // - quit
                     (
                         Button,
                         button_node.clone(),
@@ -84,6 +86,17 @@ pub fn setup_main_menu(
                             TextColor(TEXT_COLOR),
                         ),]
                     ),
+                    (
+                        Button,
+                        button_node.clone(),
+                        BackgroundColor(NORMAL_BUTTON),
+                        MainMenuButtonAction::NewGame,
+                        children![(
+                            Text::new("New Game"),
+                            button_text_font.clone(),
+                            TextColor(TEXT_COLOR),
+                        ),]
+                    ),
                     (
                         Button,
                         button_node.clone(),
@@ -144,8 +157,15 @@ pub fn update_main_menu(
                     let game_save: Result<GameSave, GetError> = pkv.get(SaveKey::GameSave);
 
                     **current_game_save = match game_save {
-                        Ok(game_save) => Some(game_save),
-                        Err(GetError::NotFound) => None,
+                        Ok(mut game_save) => {
+                            game_save.migrate();
+                            Some(game_save)
+                        }
+                        Err(GetError::NotFound) => {
+                            // Without a save, the world has to be set up first
+                            main_menu_state.set(MainMenuState::NewGame);
+                            continue;
+                        }
                         _ => panic!(
                             "An Error occured while trying to load the save state\nTry to delete the save file (/Users/username/Library/Application Support/louisweigel.sandy-factry/bevy_pkv.redb) on MacOS.\nThis WILL delete all your save data!"
                         ),
@@ -154,6 +174,9 @@ pub fn update_main_menu(
                     game_state.set(GameState::Game);
                     main_menu_state.set(MainMenuState::Hidden);
                 }
+                MainMenuButtonAction::NewGame => {
+                    main_menu_state.set(MainMenuState::NewGame);
+                }
                 MainMenuButtonAction::HowToPlay => {
                     main_menu_state.set(MainMenuState::HowToPlay);
                 }
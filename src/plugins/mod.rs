@@ -1,12 +1,17 @@
 pub mod auto_save;
 pub mod building;
+pub mod camera;
 pub mod completion;
 pub mod crafting;
-pub mod debug_camera;
+pub mod drones;
 pub mod hud;
 pub mod interaction;
+pub mod map;
 pub mod menu;
+pub mod points;
+pub mod power;
 pub mod rendering;
+pub mod signals;
 pub mod simulation;
 pub mod world;
 
@@ -15,6 +20,7 @@ pub enum RenderLayer {
     Middleground,
     Foreground,
     Items,
+    ProgressBars,
     SelectionMarker,
 }
 
@@ -25,6 +31,7 @@ impl From<RenderLayer> for f32 {
             RenderLayer::Middleground => 1.0,
             RenderLayer::Foreground => 2.0,
             RenderLayer::Items => 3.0,
+            RenderLayer::ProgressBars => 3.5,
             RenderLayer::SelectionMarker => 4.0,
         }
     }
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    content::{items::ItemType, machine_types::Machine, machines::sink::Sink},
+    game_save_types::LoadedGameSave,
+    plugins::{
+        menu::GameState,
+        simulation::{SimulationSet, SimulationUpdate},
+    },
+};
+
+// MARK: Plugin
+pub struct PointsPlugin;
+
+impl Plugin for PointsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Game), setup)
+            .add_systems(
+                SimulationUpdate,
+                collect_sunk_items.in_set(SimulationSet::PostSimulate),
+            )
+            .add_systems(OnExit(GameState::Game), cleanup);
+    }
+}
+
+// MARK: Resources
+
+/// All the items destroyed in sinks and the points earned with them
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SinkStatistics {
+    pub destroyed_items: HashMap<ItemType, u64>,
+    pub points: u64,
+}
+
+// MARK: Systems
+
+/// Initialize Resources for [PointsPlugin]
+fn setup(mut commands: Commands, game_save: Res<LoadedGameSave>) {
+    // Retrieve the statistics or start from zero
+    let sink_statistics = match &**game_save {
+        Some(game_save) => game_save.sink_statistics.clone(),
+        None => SinkStatistics::default(),
+    };
+
+    commands.insert_resource(sink_statistics);
+}
+
+/// Remove the resources for [PointsPlugin]
+fn cleanup(mut commands: Commands) {
+    commands.remove_resource::<SinkStatistics>();
+}
+
+/// Count the items destroyed by all sinks this tick
+fn collect_sunk_items(
+    mut machines: Query<&mut Machine>,
+    mut sink_statistics: ResMut<SinkStatistics>,
+) {
+    for mut machine in &mut machines {
+        let Some(sink) = machine
+            .machine_type
+            .as_mut()
+            .as_any_mut()
+            .downcast_mut::<Sink>()
+        else {
+            continue;
+        };
+
+        for (item, count) in sink.take_sunk_items() {
+            *sink_statistics.destroyed_items.entry(item).or_default() += count as u64;
+            sink_statistics.points += item.points() as u64 * count as u64;
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use network::update_power_networks;
+use overlay::{toggle_power_overlay, update_power_overlay};
+use petgraph::prelude::*;
+
+use crate::plugins::{
+    interaction::can_interact_with_world,
+    menu::GameState,
+    simulation::{SimulationSet, SimulationUpdate},
+};
+
+mod network;
+mod overlay;
+
+// MARK: Plugin
+
+pub struct PowerPlugin;
+
+impl Plugin for PowerPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Game), setup)
+            .add_systems(
+                SimulationUpdate,
+                update_power_networks.in_set(SimulationSet::PreSimulate),
+            )
+            .add_systems(
+                Update,
+                (
+                    toggle_power_overlay.run_if(can_interact_with_world),
+                    update_power_overlay,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Game)),
+            )
+            .add_systems(OnExit(GameState::Game), cleanup);
+    }
+}
+
+// MARK: Constants
+
+/// The maximum distance in tiles two power poles can be connected over
+const WIRE_RANGE: u32 = 6;
+
+// MARK: Resources
+
+/// All machines taking part in a power network
+///
+/// Poles are connected to other poles in [WIRE_RANGE] and to all other machines in their supply range.
+/// Every connected group of nodes forms its own network.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct PowerGraph(UnGraph<TilePos, ()>);
+
+/// The power statistics of all networks together, updated every simulation tick
+#[derive(Resource, Default)]
+pub struct PowerStatistics {
+    pub networks: usize,
+    pub production: u32,
+    pub consumption: u32,
+}
+
+impl PowerStatistics {
+    /// The share of the consumption that is covered by the production, from 0 to 1
+    pub fn satisfaction(&self) -> f32 {
+        if self.consumption == 0 {
+            1.0
+        } else {
+            (self.production as f32 / self.consumption as f32).min(1.0)
+        }
+    }
+}
+
+/// Whether the power overlay is shown
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct PowerOverlay(bool);
+
+// MARK: Systems
+
+fn setup(mut commands: Commands) {
+    commands.init_resource::<PowerGraph>();
+    commands.init_resource::<PowerStatistics>();
+    commands.init_resource::<PowerOverlay>();
+}
+
+fn cleanup(mut commands: Commands) {
+    commands.remove_resource::<PowerGraph>();
+    commands.remove_resource::<PowerStatistics>();
+    commands.remove_resource::<PowerOverlay>();
+}
+
+/// The distance between two tiles, counting diagonal steps as one
+fn tile_distance(a: TilePos, b: TilePos) -> u32 {
+    a.x.abs_diff(b.x).max(a.y.abs_diff(b.y))
+}
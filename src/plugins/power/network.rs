@@ -0,0 +1,140 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use petgraph::{prelude::*, unionfind::UnionFind};
+
+use crate::content::machine_types::{Machine, PowerRole};
+
+use super::{PowerGraph, PowerStatistics, WIRE_RANGE, tile_distance};
+
+/// Build the power networks and distribute the produced power to the machines
+pub fn update_power_networks(
+    mut machine_query: Query<(&TilePos, &mut Machine)>,
+    mut power_graph: ResMut<PowerGraph>,
+    mut power_statistics: ResMut<PowerStatistics>,
+) {
+    let mut graph = UnGraph::new_undirected();
+    let mut node_indices = HashMap::new();
+    let mut poles = Vec::new();
+    let mut other_machines = Vec::new();
+
+    // Add every machine that takes part in a power network
+    for (&tile_pos, machine) in &machine_query {
+        match machine.machine_type.power_role() {
+            PowerRole::None => continue,
+            PowerRole::Pole(supply_range) => {
+                let node_index = graph.add_node(tile_pos);
+                poles.push((node_index, tile_pos, supply_range));
+                node_indices.insert(tile_pos, node_index);
+            }
+            PowerRole::Consumer(_) | PowerRole::Producer(_) => {
+                let node_index = graph.add_node(tile_pos);
+                other_machines.push((node_index, tile_pos));
+                node_indices.insert(tile_pos, node_index);
+            }
+        }
+    }
+
+    for (i, &(pole_index, pole_pos, supply_range)) in poles.iter().enumerate() {
+        // Wire the pole to all other poles in range
+        for &(other_pole_index, other_pole_pos, _) in &poles[i + 1..] {
+            if tile_distance(pole_pos, other_pole_pos) <= WIRE_RANGE {
+                graph.add_edge(pole_index, other_pole_index, ());
+            }
+        }
+
+        // Connect all machines in the supply range
+        for &(machine_index, machine_pos) in &other_machines {
+            if tile_distance(pole_pos, machine_pos) <= supply_range {
+                graph.add_edge(pole_index, machine_index, ());
+            }
+        }
+    }
+
+    // Find out which network every node belongs to
+    let mut networks = UnionFind::new(graph.node_count());
+
+    for edge in graph.edge_references() {
+        networks.union(edge.source().index(), edge.target().index());
+    }
+
+    // Machines from saves from before power existed need power as soon as they are connected
+    for (tile_pos, mut machine) in &mut machine_query {
+        if machine.legacy_power
+            && node_indices
+                .get(tile_pos)
+                .is_some_and(|&node_index| graph.neighbors(node_index).next().is_some())
+        {
+            machine.legacy_power = false;
+        }
+    }
+
+    // Sum up the production and consumption of every network
+    let mut production = HashMap::new();
+    let mut consumption = HashMap::new();
+
+    for (tile_pos, machine) in &machine_query {
+        let Some(node_index) = node_indices.get(tile_pos) else {
+            continue;
+        };
+
+        if machine.legacy_power {
+            continue;
+        }
+
+        let network = networks.find(node_index.index());
+
+        match machine.machine_type.power_role() {
+            PowerRole::Consumer(power) => *consumption.entry(network).or_default() += power,
+            PowerRole::Producer(power) => *production.entry(network).or_default() += power,
+            PowerRole::None | PowerRole::Pole(_) => {}
+        }
+    }
+
+    // Tell every machine how much power it gets or has to give
+    for (tile_pos, mut machine) in &mut machine_query {
+        let Some(node_index) = node_indices.get(tile_pos) else {
+            continue;
+        };
+
+        let network = networks.find(node_index.index());
+        let network_production = production.get(&network).copied().unwrap_or(0);
+        let network_consumption = consumption.get(&network).copied().unwrap_or(0);
+
+        match machine.machine_type.power_role() {
+            PowerRole::Consumer(_) => {
+                machine.power.satisfaction =
+                    if machine.legacy_power || network_production >= network_consumption {
+                        1.0
+                    } else {
+                        network_production as f32 / network_consumption as f32
+                    };
+            }
+            PowerRole::Producer(_) => {
+                machine.power.load = if network_production == 0 {
+                    0.0
+                } else {
+                    (network_consumption as f32 / network_production as f32).min(1.0)
+                };
+            }
+            PowerRole::None | PowerRole::Pole(_) => {}
+        }
+    }
+
+    // Only count networks that actually have a pole in them
+    let mut pole_networks: Vec<usize> = poles
+        .iter()
+        .map(|(pole_index, _, _)| networks.find(pole_index.index()))
+        .collect();
+    pole_networks.sort_unstable();
+    pole_networks.dedup();
+
+    *power_statistics = PowerStatistics {
+        networks: pole_networks.len(),
+        production: production.values().sum(),
+        consumption: consumption.values().sum(),
+    };
+
+    power_graph.0 = graph;
+}
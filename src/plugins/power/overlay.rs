@@ -0,0 +1,69 @@
+use bevy::{
+    color::palettes::tailwind::{GREEN_400, RED_400, SKY_300, SKY_400, YELLOW_300, YELLOW_400},
+    prelude::*,
+};
+use bevy_ecs_tilemap::prelude::*;
+use petgraph::visit::EdgeRef;
+
+use crate::{
+    content::machine_types::{Machine, PowerRole},
+    plugins::world::{TILE_SIZE, tile_center},
+};
+
+use super::{PowerGraph, PowerOverlay};
+
+/// Toggle the power overlay with P
+pub fn toggle_power_overlay(
+    mut power_overlay: ResMut<PowerOverlay>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    if keys.just_pressed(KeyCode::KeyP) {
+        **power_overlay = !**power_overlay;
+    }
+}
+
+/// Draw the wires and supply ranges of the power poles
+/// and tint every machine by how well it is powered
+pub fn update_power_overlay(
+    power_overlay: Res<PowerOverlay>,
+    power_graph: Res<PowerGraph>,
+    mut machine_tiles: Query<(&Machine, &TilePos, &mut TileColor)>,
+    mut gizmos: Gizmos,
+) {
+    if !**power_overlay {
+        // Reset the tint once after the overlay was hidden
+        if power_overlay.is_changed() {
+            for (_, _, mut tile_color) in &mut machine_tiles {
+                *tile_color = TileColor::default();
+            }
+        }
+
+        return;
+    }
+
+    for (machine, &tile_pos, mut tile_color) in &mut machine_tiles {
+        tile_color.0 = match machine.machine_type.power_role() {
+            PowerRole::None => Color::srgba(1.0, 1.0, 1.0, 0.3),
+            PowerRole::Consumer(_) => {
+                Color::from(RED_400).mix(&GREEN_400.into(), machine.power.satisfaction)
+            }
+            PowerRole::Producer(_) => YELLOW_300.into(),
+            PowerRole::Pole(supply_range) => {
+                // Show the area this pole supplies
+                let size = (supply_range * 2 + 1) as f32 * TILE_SIZE.x;
+                gizmos.rect_2d(tile_center(tile_pos), Vec2::splat(size), SKY_400);
+
+                SKY_300.into()
+            }
+        };
+    }
+
+    // Draw the wires
+    for edge in power_graph.edge_references() {
+        gizmos.line_2d(
+            tile_center(power_graph[edge.source()]),
+            tile_center(power_graph[edge.target()]),
+            YELLOW_400,
+        );
+    }
+}
@@ -2,102 +2,276 @@ use std::collections::HashMap;
 
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
-use sandy_factry_helpers::tilemap::{TilemapSettings, generate_tilemap_layer, remove_tile};
+use strum::IntoEnumIterator;
 
 use crate::{
-    content::{items::Item, machine_types::Machine},
+    content::{
+        items::{Item, ItemType},
+        machine_types::{Machine, Side},
+        machines::belt::TieredBelt,
+    },
     plugins::{
         RenderLayer,
         building::{Foreground, foreground_objects::ForegroundObject},
-        rendering::ItemLayer,
-        world::{MAP_SIZE, MAP_TYPE, TILE_SIZE},
+        rendering::{ItemAtlas, ItemPaths, ItemSprite, ItemSprites},
+        simulation::SimulationTimer,
+        world::{MAP_SIZE, TILE_SIZE, tile_center},
     },
 };
 
-pub fn setup_item_tilemap(mut commands: Commands, asset_server: Res<AssetServer>) {
-    let texture_handle = asset_server.load("item_tiles.png");
-
-    // generate tilemap layer for items
-    generate_tilemap_layer(
-        &mut commands,
-        texture_handle,
-        RenderLayer::Items.into(),
-        TilemapSettings::new(MAP_SIZE, TILE_SIZE, MAP_TYPE, TILE_SIZE.into()),
-        ItemLayer,
-        |_| -> Option<(TileTextureIndex, Item)> { None },
-    );
+pub fn setup_item_atlas(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut texture_atlas_layouts: ResMut<Assets<TextureAtlasLayout>>,
+) {
+    let texture_count = ItemType::iter()
+        .map(|item_type| TileTextureIndex::from(item_type).0)
+        .max()
+        .expect("There should be at least one item type")
+        + 1;
+
+    commands.insert_resource(ItemAtlas {
+        image: asset_server.load("item_tiles.png"),
+        layout: texture_atlas_layouts.add(TextureAtlasLayout::from_grid(
+            UVec2::new(TILE_SIZE.x as u32, TILE_SIZE.y as u32),
+            texture_count,
+            1,
+            None,
+            None,
+        )),
+    });
 }
 
-/// Renders the items on the tilemap when they're on a belt or something similar
-pub fn update_item_tilemap(
+pub fn setup(mut commands: Commands) {
+    commands.init_resource::<ItemSprites>();
+    commands.init_resource::<ItemPaths>();
+}
+
+/// Place the items of every machine that renders its items along the machine's path
+///
+/// Every item glides from where it was shown in the last tick to its new place,
+/// items that entered the machine this tick come from the tile they left.
+pub fn update_item_paths(
     mut commands: Commands,
-    tilemap_q: Single<(Entity, &mut TileStorage), With<ItemLayer>>,
     foreground_tiles: Query<(&TilePos, &TileTextureIndex, &Machine), With<Foreground>>,
-    item_tiles: Query<(Entity, &TilePos, &Item)>,
+    mut sprites: Query<(&mut Item, &mut ItemSprite, &mut Sprite)>,
+    item_atlas: Res<ItemAtlas>,
+    mut item_sprites: ResMut<ItemSprites>,
+    mut item_paths: ResMut<ItemPaths>,
 ) {
-    let (tilemap_entity, mut tile_storage) = tilemap_q.into_inner();
-
-    // List all tiles that need to have an item rendered
-    let mut desired_items_state = HashMap::new();
-
-    foreground_tiles
-        .iter()
-        .filter(|&(_, tile_texture_index, _)| {
-            // Check if the tile is should render items AND has an item on it
-            ForegroundObject::from(*tile_texture_index).should_render_item()
-        })
-        .for_each(|(tile_pos, _, machine)| {
-            // `input_items` and `output_items` together should only have one
-
-            let mut all_items = machine.input_items.all();
-
-            if let Some(output_items) = &machine.output_items {
-                all_items.extend(output_items.get_items());
-            }
-
-            match all_items.front() {
-                None => {}
-                Some(&&item) if all_items.len() == 1 => {
-                    desired_items_state.insert(*tile_pos, item);
-                }
-                Some(_) => {
-                    panic!("There should only be one item on renderable machines at any given time")
+    // List all the items that need to be rendered, front first
+    let mut tile_items = HashMap::new();
+
+    for (tile_pos, tile_texture_index, machine) in &foreground_tiles {
+        let foreground_object = ForegroundObject::from(*tile_texture_index);
+
+        if !foreground_object.should_render_item() {
+            continue;
+        }
+
+        let mut items = Vec::new();
+
+        if let Some(output_items) = &machine.output_items {
+            items.extend(output_items.get_items().iter().copied());
+        }
+
+        items.extend(machine.input_items.all().into_iter().copied());
+
+        // Faster belts hold more items, which are spread out over the belt
+        let capacity = machine
+            .machine_type
+            .as_ref()
+            .as_any()
+            .downcast_ref::<TieredBelt>()
+            .map_or(1, |tiered_belt| tiered_belt.tier.capacity())
+            .max(items.len());
+
+        tile_items.insert(*tile_pos, (foreground_object, items, capacity));
+    }
+
+    // Items move to the front of their machine, so the items that are still in a machine
+    // are the back of the items it had in the last tick, the others left it
+    let mut stayed_items = HashMap::new();
+    let mut left_items = HashMap::new();
+
+    for (tile_pos, previous_items) in item_paths.iter() {
+        let items = tile_items
+            .get(tile_pos)
+            .map_or(&[][..], |(_, items, _)| items.as_slice());
+
+        let left_count = (0..previous_items.len())
+            .find(|&left_count| {
+                let stayed = &previous_items[left_count..];
+
+                stayed.len() <= items.len()
+                    && stayed
+                        .iter()
+                        .zip(items)
+                        .all(|((item_type, _), item)| *item_type == item.item_type)
+            })
+            .unwrap_or(previous_items.len());
+
+        left_items.insert(*tile_pos, &previous_items[..left_count]);
+        stayed_items.insert(*tile_pos, &previous_items[left_count..]);
+    }
+
+    let mut new_item_paths = HashMap::new();
+    let mut new_item_sprites = HashMap::new();
+
+    for (tile_pos, (foreground_object, items, capacity)) in &tile_items {
+        let path = ItemPath::new(*tile_pos, foreground_object);
+
+        let stayed = stayed_items.get(tile_pos).copied().unwrap_or_default();
+        let entered = path
+            .input_side
+            .and_then(|input_side| neighbor(*tile_pos, input_side))
+            .and_then(|neighbor_pos| left_items.get(&neighbor_pos))
+            .copied()
+            .unwrap_or_default();
+
+        let mut positions = Vec::new();
+
+        for (index, item) in items.iter().enumerate() {
+            let to = path.point(1.0 - (index as f32 + 0.5) / *capacity as f32);
+
+            let from = match stayed.get(index) {
+                Some(&(_, from)) => from,
+                None => entered
+                    .get(index - stayed.len())
+                    .filter(|(item_type, _)| *item_type == item.item_type)
+                    .map_or(path.start, |&(_, from)| from),
+            };
+
+            positions.push((item.item_type, to));
+
+            let item_sprite = ItemSprite {
+                tile_pos: *tile_pos,
+                from,
+                to,
+            };
+            let texture_index = TileTextureIndex::from(item.item_type).0 as usize;
+            let custom_size = Some(Vec2::from(TILE_SIZE) * (1.0 / *capacity as f32).max(0.5));
+
+            // Reuse the sprite that was at this place in the machine, if there was one
+            let reused_sprite = item_sprites
+                .remove(&(*tile_pos, index))
+                .filter(|&entity| sprites.contains(entity));
+
+            let entity = match reused_sprite {
+                Some(entity) => {
+                    let (mut sprite_item, mut sprite_path, mut sprite) =
+                        sprites.get_mut(entity).expect("The sprite should exist");
+
+                    *sprite_item = *item;
+                    *sprite_path = item_sprite;
+                    sprite.custom_size = custom_size;
+
+                    if let Some(texture_atlas) = &mut sprite.texture_atlas {
+                        texture_atlas.index = texture_index;
+                    }
+
+                    entity
                 }
-            }
-        });
-
-    // Check which tiles already have a rendered item and check if it's supposed to persist to the next frame
-    for (entity, tile_pos, item) in item_tiles.iter() {
-        match desired_items_state.get(tile_pos) {
-            Some(desired_item) if item.item_type == desired_item.item_type => {
-                desired_items_state.remove(tile_pos);
-            }
-            _ => remove_tile(&mut commands, &mut tile_storage, entity, tile_pos),
+                None => commands
+                    .spawn((
+                        Sprite {
+                            image: item_atlas.image.clone(),
+                            texture_atlas: Some(TextureAtlas {
+                                layout: item_atlas.layout.clone(),
+                                index: texture_index,
+                            }),
+                            custom_size,
+                            ..default()
+                        },
+                        Transform::from_translation(from.extend(RenderLayer::Items.into())),
+                        *item,
+                        item_sprite,
+                    ))
+                    .id(),
+            };
+
+            new_item_sprites.insert((*tile_pos, index), entity);
         }
+
+        new_item_paths.insert(*tile_pos, positions);
     }
 
-    // All the remaining tiles in desired_items_state need to be rendered
-    for (tile_pos, item) in desired_items_state.iter() {
-        let tile_entity = commands
-            .spawn((
-                TileBundle {
-                    position: *tile_pos,
-                    tilemap_id: TilemapId(tilemap_entity),
-                    texture_index: (**item).into(),
-                    ..Default::default()
-                },
-                *item,
-            ))
-            .id();
-
-        commands.entity(tilemap_entity).add_child(tile_entity);
-        tile_storage.set(tile_pos, tile_entity);
+    // The remaining sprites belong to items that aren't rendered anymore
+    for entity in item_sprites.values() {
+        commands.entity(*entity).despawn();
     }
+
+    **item_sprites = new_item_sprites;
+    **item_paths = new_item_paths;
 }
 
-pub fn cleanup(
-    mut commands: Commands,
-    item_tilemap: Single<Entity, (With<TileStorage>, With<ItemLayer>)>,
+/// Move the items along their path depending on how far the current simulation tick is
+pub fn move_item_sprites(
+    simulation_timer: Res<SimulationTimer>,
+    mut sprites: Query<(&ItemSprite, &mut Transform)>,
 ) {
-    commands.entity(item_tilemap.entity()).despawn();
+    let progress = simulation_timer.fraction();
+
+    for (item_sprite, mut transform) in &mut sprites {
+        let position = item_sprite.from.lerp(item_sprite.to, progress);
+
+        transform.translation.x = position.x;
+        transform.translation.y = position.y;
+    }
+}
+
+pub fn cleanup(mut commands: Commands, sprites: Query<Entity, With<ItemSprite>>) {
+    for entity in &sprites {
+        commands.entity(entity).despawn();
+    }
+
+    commands.remove_resource::<ItemSprites>();
+    commands.remove_resource::<ItemPaths>();
+}
+
+/// The path items take through a tile, from the side they enter to the side they leave
+struct ItemPath {
+    input_side: Option<Side>,
+    start: Vec2,
+    center: Vec2,
+    end: Vec2,
+}
+
+impl ItemPath {
+    fn new(tile_pos: TilePos, foreground_object: &ForegroundObject) -> Self {
+        let center = tile_center(tile_pos);
+
+        let input_side = first_side(foreground_object.get_input_sides());
+        let output_side = first_side(foreground_object.get_output_sides());
+
+        // Machines without an input or output side start or end their path in the middle
+        let edge = |side: Option<Side>| {
+            side.map_or(center, |side| {
+                center + side.as_ivec2().as_vec2() * Vec2::from(TILE_SIZE) / 2.0
+            })
+        };
+
+        Self {
+            input_side,
+            start: edge(input_side),
+            center,
+            end: edge(output_side),
+        }
+    }
+
+    /// The point at `t` along the path, `0` being the start and `1` the end
+    fn point(&self, t: f32) -> Vec2 {
+        // A quadratic bezier curve, so the path is straight on straight belts and round on curved ones
+        self.start * (1.0 - t) * (1.0 - t) + self.center * 2.0 * (1.0 - t) * t + self.end * t * t
+    }
+}
+
+fn first_side(sides: Option<Vec<Side>>) -> Option<Side> {
+    sides.and_then(|sides| sides.first().copied())
+}
+
+fn neighbor(tile_pos: TilePos, side: Side) -> Option<TilePos> {
+    let neighbor_pos = UVec2::from(tile_pos).as_ivec2() + side.as_ivec2();
+
+    TilePos::from_i32_pair(neighbor_pos.x, neighbor_pos.y, &MAP_SIZE)
 }
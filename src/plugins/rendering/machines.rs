@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use bevy::{prelude::*, sprite::Anchor};
+use bevy_ecs_tilemap::prelude::*;
+
+use crate::{
+    content::{
+        machine_types::Machine,
+        machines::{crafter::Crafter, furnace::Furnace},
+    },
+    plugins::{
+        RenderLayer,
+        building::{Foreground, HoverBuilding, MachinePart, foreground_objects::ForegroundObject},
+        rendering::{
+            ANIMATED_TEXTURES, PROGRESS_BAR_BACKGROUND_COLOR, PROGRESS_BAR_COLOR,
+            PROGRESS_BAR_HEIGHT, ProgressBarFill, ProgressBars, WORKING_ANIMATION_FRAMES,
+            WORKING_ANIMATION_SPEED, WORKING_ANIMATION_START,
+        },
+        world::{TILE_SIZE, tile_center},
+    },
+};
+
+pub fn setup(mut commands: Commands) {
+    commands.init_resource::<ProgressBars>();
+}
+
+/// Play the working animation on the tiles of every working machine and stop it on all the others
+pub fn animate_machines(
+    mut commands: Commands,
+    machines: Query<(&TilePos, &Machine), With<Foreground>>,
+    foreground_tiles: Query<
+        (
+            Entity,
+            &TilePos,
+            &TileTextureIndex,
+            Option<&MachinePart>,
+            Option<&AnimatedTile>,
+        ),
+        (With<Foreground>, Without<HoverBuilding>),
+    >,
+) {
+    let working_machines: HashSet<TilePos> = machines
+        .iter()
+        .filter(|(_, machine)| machine.is_working())
+        .map(|(tile_pos, _)| *tile_pos)
+        .collect();
+
+    for (entity, tile_pos, tile_texture_index, machine_part, current_animation) in &foreground_tiles
+    {
+        // The parts of bigger machines are animated with the machine on their south west tile
+        let origin = machine_part.map_or(*tile_pos, |machine_part| **machine_part);
+
+        let animation =
+            working_animation(*tile_texture_index).filter(|_| working_machines.contains(&origin));
+
+        match (animation, current_animation) {
+            (Some(animation), Some(current_animation))
+                if animation.start == current_animation.start => {}
+            (Some(animation), _) => {
+                commands.entity(entity).insert(animation);
+            }
+            (None, Some(_)) => {
+                commands.entity(entity).remove::<AnimatedTile>();
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// Show how far every crafter and furnace is with the item it's making in a bar under it
+pub fn update_progress_bars(
+    mut commands: Commands,
+    machines: Query<(&TilePos, &TileTextureIndex, &Machine), With<Foreground>>,
+    mut progress_bar_fills: Query<&mut Transform, With<ProgressBarFill>>,
+    mut progress_bars: ResMut<ProgressBars>,
+) {
+    let mut unused_progress_bars = std::mem::take(&mut **progress_bars);
+
+    for (tile_pos, tile_texture_index, machine) in &machines {
+        let machine_type = machine.machine_type.as_ref().as_any();
+
+        if !machine_type.is::<Crafter>() && !machine_type.is::<Furnace>() {
+            continue;
+        }
+
+        let Some(progress) = machine.machine_type.progress() else {
+            continue;
+        };
+
+        let progress_bar = match unused_progress_bars.remove(tile_pos) {
+            Some(progress_bar @ (_, fill)) => {
+                if let Ok(mut fill_transform) = progress_bar_fills.get_mut(fill) {
+                    fill_transform.scale.x = progress;
+                }
+
+                progress_bar
+            }
+            None => spawn_progress_bar(
+                &mut commands,
+                *tile_pos,
+                ForegroundObject::from(*tile_texture_index).size(),
+                progress,
+            ),
+        };
+
+        progress_bars.insert(*tile_pos, progress_bar);
+    }
+
+    // The remaining bars belong to machines that stopped or were removed
+    for (progress_bar, _) in unused_progress_bars.values() {
+        commands.entity(*progress_bar).despawn();
+    }
+}
+
+pub fn cleanup(mut commands: Commands, progress_bars: Res<ProgressBars>) {
+    for (progress_bar, _) in progress_bars.values() {
+        commands.entity(*progress_bar).despawn();
+    }
+
+    commands.remove_resource::<ProgressBars>();
+}
+
+/// The animation played on a tile with the texture `tile_texture_index` while its machine works
+///
+/// `None` if the texture isn't animated.
+fn working_animation(tile_texture_index: TileTextureIndex) -> Option<AnimatedTile> {
+    let position = ANIMATED_TEXTURES
+        .into_iter()
+        .flatten()
+        .position(|texture_index| texture_index == tile_texture_index.0)? as u32;
+
+    let start = WORKING_ANIMATION_START + position * WORKING_ANIMATION_FRAMES;
+
+    Some(AnimatedTile {
+        start,
+        end: start + WORKING_ANIMATION_FRAMES,
+        speed: WORKING_ANIMATION_SPEED,
+    })
+}
+
+/// Spawn a progress bar along the bottom of the machine of `size` tiles at `origin`
+///
+/// Returns the bar and its fill.
+fn spawn_progress_bar(
+    commands: &mut Commands,
+    origin: TilePos,
+    size: u32,
+    progress: f32,
+) -> (Entity, Entity) {
+    let origin_center = tile_center(origin);
+
+    let tile_size = Vec2::from(TILE_SIZE);
+    let width = size as f32 * tile_size.x - 2.0;
+    let center = origin_center + Vec2::new((size - 1) as f32 * tile_size.x / 2.0, 0.0);
+    let position = center - Vec2::new(0.0, (tile_size.y - PROGRESS_BAR_HEIGHT) / 2.0);
+
+    let fill = commands
+        .spawn((
+            Sprite {
+                color: PROGRESS_BAR_COLOR,
+                custom_size: Some(Vec2::new(width, PROGRESS_BAR_HEIGHT)),
+                anchor: Anchor::CenterLeft,
+                ..default()
+            },
+            // The fill grows from the left end of the bar
+            Transform::from_xyz(-width / 2.0, 0.0, 0.1).with_scale(Vec3::new(progress, 1.0, 1.0)),
+            ProgressBarFill,
+        ))
+        .id();
+
+    let progress_bar = commands
+        .spawn((
+            Sprite {
+                color: PROGRESS_BAR_BACKGROUND_COLOR,
+                custom_size: Some(Vec2::new(width, PROGRESS_BAR_HEIGHT)),
+                ..default()
+            },
+            Transform::from_translation(position.extend(RenderLayer::ProgressBars.into())),
+        ))
+        .add_child(fill)
+        .id();
+
+    (progress_bar, fill)
+}
@@ -1,23 +1,100 @@
-use belt::{cleanup, setup_item_tilemap, update_item_tilemap};
+use std::{collections::HashMap, ops::RangeInclusive};
+
+use belt::{cleanup, move_item_sprites, setup, setup_item_atlas, update_item_paths};
 use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use machines::{animate_machines, update_progress_bars};
+
+use crate::content::items::ItemType;
 
-use super::menu::GameState;
+use super::{
+    interaction::game_not_paused,
+    menu::GameState,
+    simulation::{SimulationSet, SimulationUpdate},
+};
 
 mod belt;
+mod machines;
+
+// MARK: Constants
+
+/// The textures of the machines that are animated while they work, in the order of their animations
+const ANIMATED_TEXTURES: [RangeInclusive<u32>; 5] =
+    [34..=37, 97..=100, 38..=41, 101..=116, 42..=49];
+
+/// The texture of the first frame of the first working animation
+const WORKING_ANIMATION_START: u32 = 120;
+
+/// How many frames the working animation of every animated texture has
+const WORKING_ANIMATION_FRAMES: u32 = 2;
 
-#[derive(Component, Clone, Copy)]
-pub struct ItemLayer;
+/// How fast the working animations play
+const WORKING_ANIMATION_SPEED: f32 = 4.0;
+
+/// How high the progress bars under the machines are
+const PROGRESS_BAR_HEIGHT: f32 = 1.5;
+
+const PROGRESS_BAR_COLOR: Color = Color::srgb(0.35, 0.85, 0.35);
+
+const PROGRESS_BAR_BACKGROUND_COLOR: Color = Color::srgba(0.0, 0.0, 0.0, 0.7);
 
 // MARK: Plugin
 pub struct RenderingPlugin;
 
 impl Plugin for RenderingPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::Game), setup_item_tilemap)
+        app.add_systems(Startup, setup_item_atlas)
+            .add_systems(OnEnter(GameState::Game), (setup, machines::setup))
             .add_systems(
-                Update,
-                update_item_tilemap.run_if(in_state(GameState::Game)),
+                SimulationUpdate,
+                (update_item_paths, animate_machines, update_progress_bars)
+                    .in_set(SimulationSet::PostSimulate),
             )
-            .add_systems(OnExit(GameState::Game), cleanup);
+            .add_systems(Update, move_item_sprites.run_if(game_not_paused))
+            .add_systems(OnExit(GameState::Game), (cleanup, machines::cleanup));
     }
 }
+
+// MARK: Resources
+
+/// The texture atlas of all the item textures
+///
+/// Created once when the game starts, for every sprite showing an item.
+#[derive(Resource)]
+pub struct ItemAtlas {
+    pub image: Handle<Image>,
+    pub layout: Handle<TextureAtlasLayout>,
+}
+
+/// The sprite of every item on a belt or something similar, by its tile and its place in the machine
+#[derive(Resource, Default, Deref, DerefMut)]
+struct ItemSprites(HashMap<(TilePos, usize), Entity>);
+
+/// The items every tile showed after the last tick, front first, and where they were shown
+///
+/// Used to find out where the items of the next tick come from.
+#[derive(Resource, Default, Deref, DerefMut)]
+struct ItemPaths(HashMap<TilePos, Vec<(ItemType, Vec2)>>);
+
+/// The progress bar of every crafter and furnace that is making something and its fill, by the machine's tile
+#[derive(Resource, Default, Deref, DerefMut)]
+struct ProgressBars(HashMap<TilePos, (Entity, Entity)>);
+
+// MARK: Components
+
+/// An item gliding along the path of the machine it's in during a simulation tick
+#[derive(Component)]
+pub struct ItemSprite {
+    /// The tile of the machine the item is in
+    pub tile_pos: TilePos,
+
+    /// Where the item was at the start of the tick
+    from: Vec2,
+
+    /// Where the item is at the end of the tick
+    to: Vec2,
+}
+
+/// The part of a progress bar that grows with the progress of its machine
+#[derive(Component)]
+struct ProgressBarFill;
@@ -0,0 +1,57 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use network::update_signal_networks;
+use petgraph::prelude::*;
+use wires::draw_signal_wires;
+
+use crate::plugins::{
+    menu::GameState,
+    simulation::{SimulationSet, SimulationUpdate},
+};
+
+mod network;
+mod wires;
+
+// MARK: Plugin
+
+pub struct SignalsPlugin;
+
+impl Plugin for SignalsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(GameState::Game), setup)
+            .add_systems(
+                SimulationUpdate,
+                update_signal_networks.in_set(SimulationSet::PreSimulate),
+            )
+            .add_systems(Update, draw_signal_wires.run_if(in_state(GameState::Game)))
+            .add_systems(OnExit(GameState::Game), cleanup);
+    }
+}
+
+// MARK: Constants
+
+/// The maximum distance in tiles two signal poles can be connected over
+const WIRE_RANGE: u32 = 6;
+
+// MARK: Resources
+
+/// The signal poles and the wires between them
+///
+/// Every connected group of poles forms its own network sharing the item counts of all machines around its poles.
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct SignalGraph(UnGraph<TilePos, ()>);
+
+// MARK: Systems
+
+fn setup(mut commands: Commands) {
+    commands.init_resource::<SignalGraph>();
+}
+
+fn cleanup(mut commands: Commands) {
+    commands.remove_resource::<SignalGraph>();
+}
+
+/// The distance between two tiles, counting diagonal steps as one
+fn tile_distance(a: TilePos, b: TilePos) -> u32 {
+    a.x.abs_diff(b.x).max(a.y.abs_diff(b.y))
+}
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+use petgraph::{prelude::*, unionfind::UnionFind};
+
+use crate::content::{items::ItemType, machine_types::Machine, machines::signal_pole::SignalPole};
+
+use super::{SignalGraph, WIRE_RANGE, tile_distance};
+
+/// Sum up the item counts of every signal network and enable or disable the machines with a signal condition
+pub fn update_signal_networks(
+    mut machine_query: Query<(&TilePos, &mut Machine)>,
+    mut signal_graph: ResMut<SignalGraph>,
+) {
+    let mut graph = UnGraph::new_undirected();
+    let mut poles = Vec::new();
+
+    for (&tile_pos, machine) in &machine_query {
+        if machine
+            .machine_type
+            .as_ref()
+            .as_any()
+            .downcast_ref::<SignalPole>()
+            .is_some()
+        {
+            poles.push((graph.add_node(tile_pos), tile_pos));
+        }
+    }
+
+    // Wire every pole to all other poles in range
+    for (i, &(pole_index, pole_pos)) in poles.iter().enumerate() {
+        for &(other_pole_index, other_pole_pos) in &poles[i + 1..] {
+            if tile_distance(pole_pos, other_pole_pos) <= WIRE_RANGE {
+                graph.add_edge(pole_index, other_pole_index, ());
+            }
+        }
+    }
+
+    // Find out which network every pole belongs to
+    let mut networks = UnionFind::new(graph.node_count());
+
+    for edge in graph.edge_references() {
+        networks.union(edge.source().index(), edge.target().index());
+    }
+
+    // The networks of all poles a machine is in range of
+    let networks_in_range = |tile_pos: TilePos| {
+        let mut machine_networks: Vec<usize> = poles
+            .iter()
+            .filter(|(_, pole_pos)| tile_distance(*pole_pos, tile_pos) <= SignalPole::RANGE)
+            .map(|(pole_index, _)| networks.find(pole_index.index()))
+            .collect();
+        machine_networks.sort_unstable();
+        machine_networks.dedup();
+        machine_networks
+    };
+
+    // Count the stored items of every network
+    let mut signals: HashMap<usize, HashMap<ItemType, u32>> = HashMap::new();
+
+    for (&tile_pos, machine) in &machine_query {
+        let Some(inventory) = machine.machine_type.inventory() else {
+            continue;
+        };
+
+        for network in networks_in_range(tile_pos) {
+            let network_signals = signals.entry(network).or_default();
+
+            for slot in inventory {
+                if let Some(item) = slot.item {
+                    *network_signals.entry(item).or_default() += slot.count as u32;
+                }
+            }
+        }
+    }
+
+    // Check the conditions with the signals of all networks around each machine
+    for (&tile_pos, mut machine) in &mut machine_query {
+        let Some(&condition) = machine.machine_type.signal_condition() else {
+            continue;
+        };
+
+        let mut machine_signals = HashMap::new();
+
+        for network in networks_in_range(tile_pos) {
+            for (&item, &count) in signals.get(&network).into_iter().flatten() {
+                *machine_signals.entry(item).or_default() += count;
+            }
+        }
+
+        machine.disabled_by_signal = !condition.is_met(&machine_signals);
+    }
+
+    signal_graph.0 = graph;
+}
@@ -0,0 +1,34 @@
+use bevy::{
+    color::palettes::tailwind::{GREEN_400, RED_500},
+    prelude::*,
+};
+use bevy_ecs_tilemap::prelude::*;
+use petgraph::visit::EdgeRef;
+
+use crate::{
+    content::machine_types::Machine,
+    plugins::world::{TILE_SIZE, tile_center},
+};
+
+use super::SignalGraph;
+
+/// Draw the wires between the signal poles and mark the machines that are stopped by their signal condition
+pub fn draw_signal_wires(
+    signal_graph: Res<SignalGraph>,
+    machine_query: Query<(&Machine, &TilePos)>,
+    mut gizmos: Gizmos,
+) {
+    for edge in signal_graph.edge_references() {
+        gizmos.line_2d(
+            tile_center(signal_graph[edge.source()]),
+            tile_center(signal_graph[edge.target()]),
+            GREEN_400,
+        );
+    }
+
+    for (machine, &tile_pos) in &machine_query {
+        if machine.disabled_by_signal {
+            gizmos.rect_2d(tile_center(tile_pos), TILE_SIZE.into(), RED_500);
+        }
+    }
+}
@@ -1,38 +1,33 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 use bevy::prelude::*;
 use bevy_ecs_tilemap::{helpers::square_grid::neighbors::Neighbors, prelude::*};
-use dyn_clone::clone_box;
 use itertools::Itertools;
 use petgraph::Graph;
 use sandy_factry_helpers::graph::{add_edge_if_not_exists, get_or_create_node};
 
 use crate::{
     Direction,
-    content::machine_types::{Machine, TunnelType},
+    content::{
+        machine_types::{Machine, TunnelType},
+        machines::drone_station::{DroneStation, DroneStationMode},
+    },
     plugins::{
         building::{
-            BuildEvent, BuildingInput, BuildingOutput, foreground_objects::ForegroundObject,
+            BuildEvent, MachinePart, foreground_objects::ForegroundObject,
+            tunnels::find_tunnel_output,
         },
         world::MAP_SIZE,
     },
 };
 
-use super::SimulationGraph;
-
-const MAXIMUM_TUNNEL_DISTANCE: u8 = 5;
+use super::{SimulationEdge, SimulationGraph};
 
 /// Build a graph from the world representation
 pub fn build_graph(
     mut _build_events: EventReader<BuildEvent>,
-    tile_query: Query<(
-        Entity,
-        &TilePos,
-        &TileTextureIndex,
-        &BuildingInput,
-        &BuildingOutput,
-        &Machine,
-    )>,
+    tile_query: Query<(Entity, &TilePos, &TileTextureIndex, &Machine)>,
+    machine_part_query: Query<(&TilePos, &MachinePart)>,
     mut simulation_graph: ResMut<SimulationGraph>,
 ) {
     // if build_events.is_empty() {
@@ -54,7 +49,27 @@ pub fn build_graph(
     let mut next = VecDeque::new();
     let remaining_tiles: VecDeque<TilePos> = tile_query
         .iter()
-        .map(|(_, &tile_pos, _, _, _, _)| tile_pos)
+        .map(|(_, &tile_pos, _, _)| tile_pos)
+        .collect();
+
+    // Look up tables for finding connected machines and tunnel partners
+    let foreground_objects: HashMap<TilePos, ForegroundObject> = tile_query
+        .iter()
+        .map(|(_, &tile_pos, &tile_texture_index, _)| (tile_pos, tile_texture_index.into()))
+        .collect();
+    let machines: HashMap<TilePos, &Machine> = tile_query
+        .iter()
+        .map(|(_, &tile_pos, _, machine)| (tile_pos, machine))
+        .collect();
+    // The tile of the machine covering each tile, because bigger machines only store their data on one tile
+    let machine_origins: HashMap<TilePos, TilePos> = tile_query
+        .iter()
+        .map(|(_, &tile_pos, _, _)| (tile_pos, tile_pos))
+        .chain(
+            machine_part_query
+                .iter()
+                .map(|(&tile_pos, machine_part)| (tile_pos, **machine_part)),
+        )
         .collect();
 
     let first_tile = tile_query.iter().next();
@@ -70,22 +85,17 @@ pub fn build_graph(
         let neighbors =
             Neighbors::get_square_neighboring_positions(&current_tile_pos, &MAP_SIZE, false);
 
-        let (_, _, &tile_texture_index, tile_building_input, tile_building_output, tile_machine) =
-            tile_query
-                .iter()
-                .find(|&(_, &tile_pos, _, _, _, _)| tile_pos == current_tile_pos)
-                .expect(
-                    "This tile should exist in the world because we got it from the world earlier.",
-                );
+        let (_, _, &tile_texture_index, tile_machine) = tile_query
+            .iter()
+            .find(|&(_, &tile_pos, _, _)| tile_pos == current_tile_pos)
+            .expect(
+                "This tile should exist in the world because we got it from the world earlier.",
+            );
 
         let tile_foreground_object = ForegroundObject::from(tile_texture_index);
         let output_sides = tile_foreground_object.get_output_sides();
 
-        let building = Machine::new(
-            clone_box(&*tile_machine.machine_type),
-            tile_machine.input_items.clone(),
-            tile_machine.output_items.clone(),
-        );
+        let building = tile_machine.clone();
         let current_node_index =
             get_or_create_node(&mut factory_graph, (building, &current_tile_pos));
 
@@ -93,7 +103,7 @@ pub fn build_graph(
             if let Some(neighbor_pos) = neighbor
                 && tile_query
                     .iter()
-                    .any(&|(_, &tile_pos, _, _, _, _)| tile_pos == neighbor_pos)
+                    .any(&|(_, &tile_pos, _, _)| tile_pos == neighbor_pos)
                 && !visited.contains(&neighbor_pos)
             {
                 next.push_back(neighbor_pos);
@@ -106,6 +116,9 @@ pub fn build_graph(
         add_neighbor(neighbors.south, &mut next);
         add_neighbor(neighbors.west, &mut next);
 
+        // Inserters take and give items on any side of their neighbors
+        let side_loading = tile_machine.machine_type.connects_to_any_side();
+
         let mut connect_inputs = false;
         let mut connect_outputs = false;
         let tunnel_type = tile_foreground_object.tunnel_type();
@@ -121,44 +134,31 @@ pub fn build_graph(
 
         if connect_inputs {
             // Connect inputs
-            for input in tile_building_input.iter().flatten() {
-                let neighbor_pos = match input {
-                    Direction::North => neighbors.north,
-                    Direction::East => neighbors.east,
-                    Direction::South => neighbors.south,
-                    Direction::West => neighbors.west,
-                };
-
-                if let Some(neighbor_pos) = neighbor_pos
-                    && let Some((
-                        _,
-                        _,
-                        &neighbor_tile_texture_index,
-                        _,
-                        neighbor_building_output,
-                        neighbor_machine,
-                    )) = tile_query
-                        .iter()
-                        .find(|&(_, &tile_pos, _, _, _, _)| tile_pos == neighbor_pos)
-                    && let Some(outputs) = neighbor_building_output.as_ref()
-                    && outputs.iter().any(|output| output.get_opposite() == *input)
+            for (offset, input_side) in tile_foreground_object.get_input_ports() {
+                // Find the machine next to this port and check if it outputs into it
+                if let Some(neighbor_pos) = get_port_neighbor(current_tile_pos, offset, input_side)
+                    && let Some(&neighbor_origin) = machine_origins.get(&neighbor_pos)
+                    && let Some(&neighbor_foreground_object) =
+                        foreground_objects.get(&neighbor_origin)
+                    && get_ports_at(
+                        neighbor_foreground_object.get_output_ports(),
+                        neighbor_origin,
+                        neighbor_pos,
+                    )
+                    .any(|output_side| side_loading || output_side.get_opposite() == input_side)
                     && !matches!(
-                        ForegroundObject::from(neighbor_tile_texture_index).tunnel_type(),
+                        neighbor_foreground_object.tunnel_type(),
                         Some(TunnelType::Input)
                     )
                 {
-                    let building = Machine::new(
-                        clone_box(&*neighbor_machine.machine_type),
-                        neighbor_machine.input_items.clone(),
-                        neighbor_machine.output_items.clone(),
-                    );
+                    let building = machines[&neighbor_origin].clone();
                     let new_node_index =
-                        get_or_create_node(&mut factory_graph, (building, &neighbor_pos));
+                        get_or_create_node(&mut factory_graph, (building, &neighbor_origin));
                     add_edge_if_not_exists(
                         &mut factory_graph,
                         new_node_index,
                         current_node_index,
-                        *input,
+                        SimulationEdge::Adjacent(input_side),
                     );
                 }
             }
@@ -166,111 +166,149 @@ pub fn build_graph(
 
         if connect_outputs {
             // Connect outputs
-            for output in tile_building_output.iter().flatten() {
-                let neighbor_pos = match output {
-                    Direction::North => neighbors.north,
-                    Direction::East => neighbors.east,
-                    Direction::South => neighbors.south,
-                    Direction::West => neighbors.west,
-                };
-
-                if let Some(neighbor_pos) = neighbor_pos
-                    && let Some((
-                        _,
-                        _,
-                        &neighbor_tile_texture_index,
-                        neighbor_building_input,
-                        _,
-                        neighbor_machine,
-                    )) = tile_query
-                        .iter()
-                        .find(|&(_, &tile_pos, _, _, _, _)| tile_pos == neighbor_pos)
-                    && let Some(inputs) = neighbor_building_input.0.as_ref()
-                    && inputs.iter().any(|input| input.get_opposite() == *output)
+            for (offset, output_side) in tile_foreground_object.get_output_ports() {
+                // Inserters put their items into any input of a machine not facing them,
+                // the simulation picks the first one that accepts the item
+                if let Some(neighbor_pos) = get_port_neighbor(current_tile_pos, offset, output_side)
+                    && let Some(&neighbor_origin) = machine_origins.get(&neighbor_pos)
+                    && let Some(&neighbor_foreground_object) =
+                        foreground_objects.get(&neighbor_origin)
                     && !matches!(
-                        ForegroundObject::from(neighbor_tile_texture_index).tunnel_type(),
+                        neighbor_foreground_object.tunnel_type(),
                         Some(TunnelType::Output)
                     )
                 {
-                    let building = Machine::new(
-                        clone_box(&*neighbor_machine.machine_type),
-                        neighbor_machine.input_items.clone(),
-                        neighbor_machine.output_items.clone(),
-                    );
+                    let neighbor_input_sides: Vec<Direction> = get_ports_at(
+                        neighbor_foreground_object.get_input_ports(),
+                        neighbor_origin,
+                        neighbor_pos,
+                    )
+                    .collect();
+
+                    let input_sides = match neighbor_input_sides
+                        .iter()
+                        .find(|input_side| input_side.get_opposite() == output_side)
+                    {
+                        Some(&facing_input_side) => vec![facing_input_side],
+                        None if side_loading => neighbor_input_sides,
+                        None => Vec::new(),
+                    };
+
+                    if input_sides.is_empty() {
+                        continue;
+                    }
+
+                    let building = machines[&neighbor_origin].clone();
                     let new_node_index =
-                        get_or_create_node(&mut factory_graph, (building, &neighbor_pos));
-                    add_edge_if_not_exists(
-                        &mut factory_graph,
-                        current_node_index,
-                        new_node_index,
-                        output.get_opposite(),
-                    );
+                        get_or_create_node(&mut factory_graph, (building, &neighbor_origin));
+
+                    // There can be an edge for every input side, but only one per side
+                    for input_side in input_sides {
+                        let edge = SimulationEdge::Adjacent(input_side);
+
+                        if !factory_graph
+                            .edges_connecting(current_node_index, new_node_index)
+                            .any(|existing_edge| *existing_edge.weight() == edge)
+                        {
+                            factory_graph.add_edge(current_node_index, new_node_index, edge);
+                        }
+                    }
                 }
             }
         }
 
-        // Check if the current tile is a tunnel input
-        if let Some(TunnelType::Input) = tunnel_type {
-            // Get the output side of this tunnel
-            let output_side = output_sides
-                .as_ref()
-                .expect("All tunnels should have an output")
-                .iter()
-                .exactly_one()
-                .expect("This is Some, it definitely has a side inside");
-
-            // convert the TilePos to a UVec2 for easier calculation
-            let tile_pos_vec = UVec2::from(current_tile_pos);
-
-            // Try all the different possible tunnel distances
-            // This starts at one to avoid confusion when placing two tunnels right after each other
-            for i in 1..=MAXIMUM_TUNNEL_DISTANCE {
-                let searched_tile_pos = tile_pos_vec
-                    .saturating_add_signed(output_side.as_ivec2() * i as i32)
-                    .into();
-
-                // Search through all other tiles to check if a matching tunnel output exists at the location searched for
-                if let Some((_, tile_pos, _, building_input, _, machine)) = tile_query.iter().find(
-                    |&(_, &tile_pos, &tile_texture_index, building_input, _, _)| {
-                        matches!(
-                            ForegroundObject::from(tile_texture_index).tunnel_type(),
-                            Some(TunnelType::Output)
-                        ) && tile_pos == searched_tile_pos
-                            && *building_input
-                                .as_ref()
-                                .expect("All tunnels should have an input")
-                                .iter()
-                                .exactly_one()
-                                .expect("This is Some, it definitely has a side inside")
-                                == output_side.get_opposite()
-                    },
-                ) {
-                    // If found, connect the two
-                    let machine = Machine::new(
-                        clone_box(&*machine.machine_type),
-                        machine.input_items.clone(),
-                        machine.output_items.clone(),
-                    );
-                    let new_node_index =
-                        get_or_create_node(&mut factory_graph, (machine, tile_pos));
-                    add_edge_if_not_exists(
-                        &mut factory_graph,
-                        current_node_index,
-                        new_node_index,
-                        *building_input
-                            .as_ref()
-                            .expect("All tunnels should have an input")
-                            .iter()
-                            .exactly_one()
-                            .expect("This is Some, it definitely has a side inside"),
-                    );
+        // Check if the current tile is a tunnel input and connect it to its output
+        if let Some(TunnelType::Input) = tunnel_type
+            && let Some(tunnel_output_pos) = find_tunnel_output(
+                current_tile_pos,
+                tile_foreground_object,
+                &foreground_objects,
+            )
+        {
+            let tunnel_output_machine = machines
+                .get(&tunnel_output_pos)
+                .expect("The tunnel output was found in the world");
 
-                    // Stop searching for more tunnel outputs after finding the nearest
-                    break;
-                }
-            }
+            let machine = (*tunnel_output_machine).clone();
+            let new_node_index =
+                get_or_create_node(&mut factory_graph, (machine, &tunnel_output_pos));
+            add_edge_if_not_exists(
+                &mut factory_graph,
+                current_node_index,
+                new_node_index,
+                SimulationEdge::Adjacent(
+                    output_sides
+                        .as_ref()
+                        .expect("All tunnels should have an output")
+                        .iter()
+                        .exactly_one()
+                        .expect("This is Some, it definitely has a side inside")
+                        .get_opposite(),
+                ),
+            );
         }
     }
 
+    add_drone_links(&mut factory_graph);
+
     **simulation_graph = factory_graph;
 }
+
+/// Link every sending drone station to the receiving stations on the channels of its route
+///
+/// The latency of a link is the number of ticks a drone needs for it.
+fn add_drone_links(factory_graph: &mut Graph<(Machine, TilePos), SimulationEdge>) {
+    let mut senders = Vec::new();
+    let mut receivers = Vec::new();
+
+    for node_index in factory_graph.node_indices() {
+        let (machine, tile_pos) = &factory_graph[node_index];
+
+        let Some(station) = machine
+            .machine_type
+            .as_ref()
+            .as_any()
+            .downcast_ref::<DroneStation>()
+        else {
+            continue;
+        };
+
+        match station.mode {
+            DroneStationMode::Send => senders.push((node_index, *tile_pos, station.route())),
+            DroneStationMode::Receive => receivers.push((node_index, *tile_pos, station.channel)),
+        }
+    }
+
+    for (sender_index, sender_pos, route) in &senders {
+        for (receiver_index, receiver_pos, channel) in &receivers {
+            if route.contains(channel) {
+                factory_graph.add_edge(
+                    *sender_index,
+                    *receiver_index,
+                    SimulationEdge::Latency(DroneStation::flight_time(*sender_pos, *receiver_pos)),
+                );
+            }
+        }
+    }
+}
+
+/// Get the position of the tile a port at `offset` from the machine at `origin` faces
+///
+/// Returns `None` if that tile would be outside of the map.
+fn get_port_neighbor(origin: TilePos, offset: UVec2, side: Direction) -> Option<TilePos> {
+    let neighbor_pos = (UVec2::from(origin) + offset).as_ivec2() + side.as_ivec2();
+
+    TilePos::from_i32_pair(neighbor_pos.x, neighbor_pos.y, &MAP_SIZE)
+}
+
+/// Get the sides of all `ports` of the machine at `origin` that are on the tile at `tile_pos`
+fn get_ports_at(
+    ports: Vec<(UVec2, Direction)>,
+    origin: TilePos,
+    tile_pos: TilePos,
+) -> impl Iterator<Item = Direction> {
+    ports
+        .into_iter()
+        .filter(move |&(offset, _)| UVec2::from(origin) + offset == UVec2::from(tile_pos))
+        .map(|(_, side)| side)
+}
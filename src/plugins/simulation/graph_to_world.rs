@@ -1,6 +1,5 @@
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
-use dyn_clone::clone_box;
 
 use crate::content::machine_types::Machine;
 
@@ -26,8 +25,6 @@ pub fn graph_to_world(
         };
 
         // Clone the stats and items
-        machine_component.input_items = machine.input_items.clone();
-        machine_component.output_items = machine.output_items.clone();
-        machine_component.machine_type = clone_box(&*machine.machine_type);
+        *machine_component = machine.clone();
     }
 }
@@ -26,12 +26,22 @@ impl Plugin for SimulationPlugin {
                 TimerMode::Repeating,
             )))
             .add_systems(Main, tick_simulation_update)
+            .configure_sets(
+                SimulationUpdate,
+                (
+                    SimulationSet::PreSimulate,
+                    SimulationSet::Simulate,
+                    SimulationSet::PostSimulate,
+                )
+                    .chain()
+                    .run_if(game_not_paused),
+            )
             .add_systems(OnEnter(GameState::Game), setup)
             .add_systems(
                 SimulationUpdate,
                 (build_graph, simulate, graph_to_world)
                     .chain()
-                    .run_if(game_not_paused),
+                    .in_set(SimulationSet::Simulate),
             )
             .add_systems(OnExit(GameState::Game), cleanup);
     }
@@ -43,13 +53,64 @@ impl Plugin for SimulationPlugin {
 #[derive(ScheduleLabel, Hash, Debug, PartialEq, Eq, Clone, Copy)]
 pub struct SimulationUpdate;
 
+// MARK: Sets
+
+/// The phases of a single [SimulationUpdate] tick
+///
+/// The machines in the world are only copied into the simulation graph during [SimulationSet::Simulate],
+/// so systems that want to change them should run in [SimulationSet::PreSimulate]
+/// and systems that want to read the results in [SimulationSet::PostSimulate].
+#[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SimulationSet {
+    PreSimulate,
+    Simulate,
+    PostSimulate,
+}
+
 // MARK: Resources
 
 #[derive(Resource, Default, Deref, DerefMut)]
-struct SimulationGraph(Graph<(Machine, TilePos), Side>);
+pub struct SimulationGraph(Graph<(Machine, TilePos), SimulationEdge>);
+
+impl SimulationGraph {
+    /// The start and end of every link with a latency
+    pub fn latency_links(&self) -> impl Iterator<Item = (TilePos, TilePos)> {
+        self.edge_references()
+            .filter(|edge| edge.weight().latency().is_some())
+            .map(|edge| (self[edge.source()].1, self[edge.target()].1))
+    }
+}
+
+/// How items get from one machine to the next in the [SimulationGraph]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimulationEdge {
+    /// The machines are next to each other, items are moved into the input at this side of the next machine in the same tick
+    Adjacent(Side),
+    /// A long-range link, like the one between two drone stations, items need this many ticks to get to the next machine
+    Latency(u32),
+}
+
+impl SimulationEdge {
+    /// The input side of the next machine, `None` for long-range links
+    pub fn side(&self) -> Option<Side> {
+        match self {
+            SimulationEdge::Adjacent(side) => Some(*side),
+            SimulationEdge::Latency(_) => None,
+        }
+    }
+
+    /// How many ticks items need along a long-range link, `None` for neighboring machines
+    pub fn latency(&self) -> Option<u32> {
+        match self {
+            SimulationEdge::Adjacent(_) => None,
+            SimulationEdge::Latency(latency) => Some(*latency),
+        }
+    }
+}
 
+/// Times the simulation ticks, its fraction is how far the current tick is
 #[derive(Resource, Deref, DerefMut)]
-struct SimulationTimer(Timer);
+pub struct SimulationTimer(Timer);
 
 // MARK: Systems
 
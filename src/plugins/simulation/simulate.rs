@@ -1,26 +1,28 @@
 use std::collections::{HashSet, VecDeque};
 
 use bevy::{platform::collections::HashMap, prelude::*};
-use bevy_ecs_tilemap::tiles::{TilePos, TileTextureIndex};
-use petgraph::{algo::tarjan_scc, prelude::*};
+use bevy_ecs_tilemap::tiles::TilePos;
+use petgraph::{algo::tarjan_scc, prelude::*, visit::EdgeFiltered};
 
 use crate::{
-    content::machine_types::{OutputItems, Side},
-    plugins::world::{Middleground, MiddlegroundObject},
+    content::{
+        machine_types::{Machine, OutputItems, Side},
+        machines::drone_station::{DroneFlight, DroneStation},
+    },
+    plugins::world::{MiddlegroundObject, WorldChunks},
 };
 
 use super::SimulationGraph;
 
 /// Do a single simulation step of the world based on the `SimulationGraph`
-pub fn simulate(
-    mut simulation_graph: ResMut<SimulationGraph>,
-    tile_query: Query<(&TilePos, &TileTextureIndex), With<Middleground>>,
-) {
+pub fn simulate(mut simulation_graph: ResMut<SimulationGraph>, world_chunks: Res<WorldChunks>) {
     // Return if the simulation graph is empty aka there are no machines in the world
     if simulation_graph.node_count() == 0 {
         return;
     }
 
+    dispatch_drones(&mut simulation_graph);
+
     let mut made_progress = true;
     let mut first_time_ticking = true;
 
@@ -30,7 +32,9 @@ pub fn simulate(
 
         // Get all the SCCs (Strongly Connected Components) using Tarjan's algorithm
         // This function also performs a topological sort on the result
-        let scc = tarjan_scc(&**simulation_graph);
+        let scc = tarjan_scc(&EdgeFiltered::from_fn(&**simulation_graph, |edge| {
+            edge.weight().side().is_some()
+        }));
 
         let mut visited = HashSet::new();
         let mut times_machines_hit: HashMap<NodeIndex, u32> = HashMap::new();
@@ -48,8 +52,11 @@ pub fn simulate(
                 // Get all the indices of the machines, we could theoretically push to
                 let next_machine_indices: Vec<(NodeIndex, Side)> = simulation_graph
                     .edges_directed(node_index, Direction::Outgoing)
-                    .map(|next_machine_edge| {
-                        (next_machine_edge.target(), *next_machine_edge.weight())
+                    .filter_map(|next_machine_edge| {
+                        Some((
+                            next_machine_edge.target(),
+                            next_machine_edge.weight().side()?,
+                        ))
                     })
                     .collect();
 
@@ -74,8 +81,11 @@ pub fn simulate(
                     }
 
                     // Insert all neighbors we want to visit into the queue
-                    for adjacent_node in
-                        simulation_graph.neighbors_directed(node_index, Direction::Incoming)
+                    // Drones bring their items on their own, so long-range links are skipped
+                    for adjacent_node in simulation_graph
+                        .edges_directed(node_index, Direction::Incoming)
+                        .filter(|edge| edge.weight().side().is_some())
+                        .map(|edge| edge.source())
                     {
                         if !visited.contains(&adjacent_node) {
                             next_nodes.push_back(adjacent_node);
@@ -106,7 +116,7 @@ pub fn simulate(
 
                             // Perform the machine's action
                             machine.perform_action(get_middleground_object(
-                                &tile_query,
+                                &world_chunks,
                                 machine_tile_pos,
                             ));
                         }
@@ -228,8 +238,11 @@ pub fn simulate(
                     // ... because if not, all the additional steps for trying to push items can be skipped
 
                     // Insert all neighbors we want to visit into the queue
-                    for adjacent_node in
-                        simulation_graph.neighbors_directed(node_index, Direction::Incoming)
+                    // Drones bring their items on their own, so long-range links are skipped
+                    for adjacent_node in simulation_graph
+                        .edges_directed(node_index, Direction::Incoming)
+                        .filter(|edge| edge.weight().side().is_some())
+                        .map(|edge| edge.source())
                     {
                         if !visited.contains(&adjacent_node) {
                             next_nodes.push_back(adjacent_node);
@@ -242,8 +255,10 @@ pub fn simulate(
 
                     if machine.machine_type.tick_after_first() || first_time_ticking {
                         // Perform the machine's action
-                        machine
-                            .perform_action(get_middleground_object(&tile_query, machine_tile_pos));
+                        machine.perform_action(get_middleground_object(
+                            &world_chunks,
+                            machine_tile_pos,
+                        ));
                     }
                 }
             }
@@ -269,13 +284,91 @@ pub fn simulate(
 
 /// Get the middleground object at `searched_tile_pos`
 ///
-/// Returns `None` if there is no middleground object at that position
+/// Returns `None` if there is no middleground object at that position or it's mined out.
+/// The ore is read from the chunks, because they know how much of it is left.
 fn get_middleground_object(
-    tile_query: &Query<(&TilePos, &TileTextureIndex), With<Middleground>>,
+    world_chunks: &WorldChunks,
     searched_tile_pos: &mut TilePos,
 ) -> Option<MiddlegroundObject> {
-    tile_query
-        .iter()
-        .find(|(tile_pos, _)| tile_pos == &searched_tile_pos)
-        .and_then(|(_, tile_texture_index)| (*tile_texture_index).try_into().ok())
+    world_chunks
+        .ore_at(searched_tile_pos)
+        .filter(|ore_deposit| ore_deposit.amount > 0)
+        .map(|ore_deposit| ore_deposit.resource)
+}
+
+/// Send the drones of all ready sending stations along a latency link to a receiving station on the current stop of their route
+///
+/// Stations with the fewest drones on their way are served first, so every receiver of a channel gets its share.
+/// A drone waits at its station until a receiver on its current stop can take it.
+fn dispatch_drones(simulation_graph: &mut SimulationGraph) {
+    let mut senders = Vec::new();
+    let mut receivers = HashMap::new();
+
+    for node_index in simulation_graph.node_indices() {
+        let Some(station) = drone_station(&simulation_graph[node_index].0) else {
+            continue;
+        };
+
+        if station.is_ready_to_send() {
+            senders.push((node_index, station.current_stop()));
+        } else if station.can_receive() {
+            receivers.insert(node_index, (station.channel, station.incoming().len()));
+        }
+    }
+
+    for (sender_index, stop) in senders {
+        let Some((receiver_index, flight_time)) = simulation_graph
+            .edges_directed(sender_index, Direction::Outgoing)
+            .filter_map(|link| Some((link.target(), link.weight().latency()?)))
+            .filter(|(receiver_index, _)| {
+                receivers
+                    .get(receiver_index)
+                    .is_some_and(|&(channel, incoming)| {
+                        channel == stop && incoming < DroneStation::MAX_INCOMING
+                    })
+            })
+            .min_by_key(|(receiver_index, flight_time)| {
+                (receivers[receiver_index].1, *flight_time)
+            })
+        else {
+            continue;
+        };
+
+        let ((sender, sender_pos), (receiver, _)) =
+            simulation_graph.index_twice_mut(sender_index, receiver_index);
+
+        let items = drone_station_mut(sender)
+            .expect("The sender should be a drone station")
+            .take_cargo(flight_time);
+
+        drone_station_mut(receiver)
+            .expect("The receiver should be a drone station")
+            .receive(DroneFlight {
+                from: *sender_pos,
+                flight_time,
+                ticks_left: flight_time,
+                items,
+            });
+
+        receivers
+            .get_mut(&receiver_index)
+            .expect("The receiver was just found")
+            .1 += 1;
+    }
+}
+
+fn drone_station(machine: &Machine) -> Option<&DroneStation> {
+    machine
+        .machine_type
+        .as_ref()
+        .as_any()
+        .downcast_ref::<DroneStation>()
+}
+
+fn drone_station_mut(machine: &mut Machine) -> Option<&mut DroneStation> {
+    machine
+        .machine_type
+        .as_mut()
+        .as_any_mut()
+        .downcast_mut::<DroneStation>()
 }
@@ -0,0 +1,138 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+
+use crate::plugins::world::{
+    Background, CHUNK_SIZE, ChunkEvent, LoadedChunks, MAP_SIZE, Middleground, TILE_SIZE,
+    WorldChunks,
+    generation::{WorldGenerator, chunk_tile_positions},
+};
+
+/// The most chunks that are loaded from the camera in every direction, no matter how far it's zoomed out
+const MAX_LOAD_DISTANCE: i32 = 8;
+
+/// Spawn the tiles of the chunks that came into view and despawn the ones of chunks that left it
+///
+/// Chunks are generated the first time they are loaded.
+pub fn update_loaded_chunks(
+    mut commands: Commands,
+    camera: Single<(&Transform, &Projection), With<Camera2d>>,
+    world_generator: Res<WorldGenerator>,
+    mut world_chunks: ResMut<WorldChunks>,
+    mut loaded_chunks: ResMut<LoadedChunks>,
+    mut chunk_events: EventWriter<ChunkEvent>,
+    background_tilemap: Single<
+        (Entity, &mut TileStorage),
+        (With<Background>, Without<Middleground>),
+    >,
+    middleground_tilemap: Single<
+        (Entity, &mut TileStorage),
+        (With<Middleground>, Without<Background>),
+    >,
+) {
+    let (camera_transform, projection) = camera.into_inner();
+    let Projection::Orthographic(projection) = projection else {
+        return;
+    };
+
+    let (background_entity, mut background_storage) = background_tilemap.into_inner();
+    let (middleground_entity, mut middleground_storage) = middleground_tilemap.into_inner();
+
+    // Load one more chunk around the visible area, so chunks are ready before they are seen
+    let camera_chunk = world_to_chunk_pos(camera_transform.translation.xy());
+    let min_chunk = world_to_chunk_pos(camera_transform.translation.xy() + projection.area.min)
+        .max(camera_chunk - MAX_LOAD_DISTANCE)
+        - IVec2::ONE;
+    let max_chunk = world_to_chunk_pos(camera_transform.translation.xy() + projection.area.max)
+        .min(camera_chunk + MAX_LOAD_DISTANCE)
+        + IVec2::ONE;
+    let chunk_count = IVec2::new(
+        (MAP_SIZE.x / CHUNK_SIZE) as i32,
+        (MAP_SIZE.y / CHUNK_SIZE) as i32,
+    );
+    let min_chunk = min_chunk.max(IVec2::ZERO);
+    let max_chunk = max_chunk.min(chunk_count - IVec2::ONE);
+
+    let is_in_view =
+        |chunk_pos: IVec2| chunk_pos.cmpge(min_chunk).all() && chunk_pos.cmple(max_chunk).all();
+
+    // Unload the chunks out of view
+    loaded_chunks.retain(|&chunk_pos| {
+        if is_in_view(chunk_pos) {
+            return true;
+        }
+
+        for tile_pos in chunk_tile_positions(chunk_pos) {
+            for tile_storage in [&mut *background_storage, &mut *middleground_storage] {
+                if let Some(tile_entity) = tile_storage.get(&tile_pos) {
+                    commands.entity(tile_entity).despawn();
+                    tile_storage.remove(&tile_pos);
+                }
+            }
+        }
+
+        chunk_events.write(ChunkEvent::Unloaded(chunk_pos));
+
+        false
+    });
+
+    // Load the chunks that came into view
+    for x in min_chunk.x..=max_chunk.x {
+        for y in min_chunk.y..=max_chunk.y {
+            let chunk_pos = IVec2::new(x, y);
+
+            if !loaded_chunks.insert(chunk_pos) {
+                continue;
+            }
+
+            let chunk = world_chunks
+                .entry(chunk_pos)
+                .or_insert_with(|| world_generator.chunk(chunk_pos));
+            for tile_pos in chunk_tile_positions(chunk_pos) {
+                let tile_entity = commands
+                    .spawn((
+                        TileBundle {
+                            position: tile_pos,
+                            tilemap_id: TilemapId(background_entity),
+                            texture_index: world_generator.background(tile_pos).into(),
+                            ..default()
+                        },
+                        Background,
+                        ChildOf(background_entity),
+                    ))
+                    .id();
+                background_storage.set(&tile_pos, tile_entity);
+
+                // Don't spawn tiles that have already been mined out
+                if let Some(ore_deposit) = chunk.ores.get(&tile_pos)
+                    && ore_deposit.amount > 0
+                {
+                    let tile_entity = commands
+                        .spawn((
+                            TileBundle {
+                                position: tile_pos,
+                                tilemap_id: TilemapId(middleground_entity),
+                                texture_index: ore_deposit.resource.into(),
+                                ..default()
+                            },
+                            Middleground,
+                            ChildOf(middleground_entity),
+                        ))
+                        .id();
+                    middleground_storage.set(&tile_pos, tile_entity);
+                }
+            }
+
+            chunk_events.write(ChunkEvent::Loaded(chunk_pos));
+        }
+    }
+}
+
+/// The position of the chunk the world position `position` is in
+///
+/// Positions outside of the map give chunk positions outside of it as well.
+fn world_to_chunk_pos(position: Vec2) -> IVec2 {
+    let map_size = Vec2::new(MAP_SIZE.x as f32, MAP_SIZE.y as f32) * Vec2::from(TILE_SIZE);
+    let tile = ((position + map_size / 2.0) / Vec2::from(TILE_SIZE)).floor();
+
+    (tile / CHUNK_SIZE as f32).floor().as_ivec2()
+}
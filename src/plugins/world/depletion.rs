@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+use bevy_ecs_tilemap::prelude::*;
+
+use crate::{
+    content::{machine_types::Machine, machines::miner::Miner},
+    plugins::world::{Middleground, WorldChunks},
+};
+
+/// Remove the ore mined this tick from the deposits and clear the tiles that are mined out
+pub fn deplete_world_chunks(
+    mut commands: Commands,
+    mut machines: Query<(&TilePos, &mut Machine)>,
+    mut world_chunks: ResMut<WorldChunks>,
+    mut middleground_tile_storage: Single<&mut TileStorage, With<Middleground>>,
+) {
+    for (tile_pos, mut machine) in &mut machines {
+        let Some(miner) = machine
+            .machine_type
+            .as_mut()
+            .as_any_mut()
+            .downcast_mut::<Miner>()
+        else {
+            continue;
+        };
+
+        let mined_items = miner.take_mined_items();
+
+        if mined_items == 0 {
+            continue;
+        }
+
+        let Some(ore_deposit) = world_chunks.ore_at_mut(tile_pos) else {
+            continue;
+        };
+
+        ore_deposit.amount = ore_deposit.amount.saturating_sub(mined_items);
+
+        // Remove the resource tile, so miners stop mining it
+        if ore_deposit.amount == 0
+            && let Some(tile_entity) = middleground_tile_storage.get(tile_pos)
+        {
+            commands.entity(tile_entity).despawn();
+            middleground_tile_storage.remove(tile_pos);
+        }
+    }
+}
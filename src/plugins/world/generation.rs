@@ -1,70 +1,418 @@
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
 use noise::{NoiseFn, Simplex};
-use sandy_factry_helpers::tilemap::{TilemapSettings, generate_tilemap_layer};
+use sandy_factry_helpers::tilemap::{TilemapSettings, make_tilemap_bundle};
+use serde::{Deserialize, Serialize};
+use strum_macros::{Display, EnumIter};
 
-use crate::plugins::{
-    RenderLayer,
-    world::{
-        Background, BackgroundObject, MAP_SIZE, MAP_TYPE, Middleground, MiddlegroundObject, Seed,
-        TILE_SIZE,
+use crate::{
+    game_save_types::LoadedGameSave,
+    plugins::{
+        RenderLayer,
+        world::{
+            Background, BackgroundObject, CHUNK_SIZE, Chunk, LoadedChunks, MAP_SIZE, MAP_TYPE,
+            Middleground, MiddlegroundObject, NewWorldSettings, OreDeposit, Seed, TILE_SIZE,
+            WorldChunks,
+        },
     },
 };
 
-/// Run the world generation
-pub fn generation(mut commands: Commands, asset_server: Res<AssetServer>, seed: Res<Seed>) {
+/// Settings for how resources are spread over the world
+///
+/// Saved with the game, so the chunks that weren't generated yet match the rest of the world.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WorldGenerationConfig {
+    /// Roughly how much of the world is covered by ore, from 0 to 1
+    pub ore_frequency: f64,
+    /// Roughly how many tiles the ore patches and the gaps between them are across
+    pub patch_size: f64,
+    /// How much ore every resource tile holds compared to the default
+    pub richness: f64,
+    /// How many tiles the playable area in the middle of the map is across, everything outside of it is water
+    pub map_size: u32,
+    /// How many tiles the area in the middle of the map is across that holds the world of a save from before the map was split into chunks
+    ///
+    /// That area has the ore of the old world generation, so the saved machines stay on their ore.
+    pub legacy_area_size: u32,
+}
+
+impl WorldGenerationConfig {
+    /// The config for the presets chosen on the new game screen
+    pub fn from_presets(map_size: MapSizePreset, resources: ResourcePreset) -> Self {
+        let (ore_frequency, richness) = match resources {
+            ResourcePreset::Scarce => (0.2, 0.5),
+            ResourcePreset::Normal => (0.25, 1.0),
+            ResourcePreset::Rich => (0.3, 2.0),
+        };
+
+        Self {
+            ore_frequency,
+            richness,
+            map_size: map_size.size(),
+            ..default()
+        }
+    }
+}
+
+impl Default for WorldGenerationConfig {
+    fn default() -> Self {
+        Self {
+            ore_frequency: 0.25,
+            patch_size: 10.0,
+            richness: 1.0,
+            map_size: MAP_SIZE.x,
+            legacy_area_size: 0,
+        }
+    }
+}
+
+/// How big the playable area of a new world is
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+pub enum MapSizePreset {
+    Small,
+    Medium,
+    #[default]
+    Large,
+}
+
+impl MapSizePreset {
+    /// How many tiles the playable area is across
+    pub fn size(&self) -> u32 {
+        match self {
+            MapSizePreset::Small => 192,
+            MapSizePreset::Medium => 320,
+            MapSizePreset::Large => MAP_SIZE.x,
+        }
+    }
+}
+
+/// How much ore a new world has
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Display, EnumIter)]
+pub enum ResourcePreset {
+    Scarce,
+    #[default]
+    Normal,
+    Rich,
+}
+
+/// What the world generation puts at a single tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GeneratedTile {
+    pub background: BackgroundObject,
+    pub ore: Option<OreDeposit>,
+}
+
+/// Generates the world from a seed and a config
+///
+/// Every tile only depends on these and its position, so tiles and chunks can be generated in any order.
+#[derive(Resource)]
+pub struct WorldGenerator {
+    simplex: Simplex,
+    config: WorldGenerationConfig,
+}
+
+impl WorldGenerator {
+    /// The least amount of ore a resource tile holds, found at the edges of a patch
+    const MINIMUM_ORE_AMOUNT: f64 = 200.0;
+    /// The most amount of ore a resource tile holds, found in the middle of a patch
+    const MAXIMUM_ORE_AMOUNT: f64 = 1000.0;
+    /// Offsets the noise for the resource type, so it doesn't line up with the patches
+    const RESOURCE_NOISE_OFFSET: f64 = 100.0;
+    /// How much the noise was scaled for the ore patches of the legacy area
+    const LEGACY_PATCH_SCALE: f64 = 0.1;
+    /// Above which noise value a tile of the legacy area holds ore
+    const LEGACY_ORE_THRESHOLD: f64 = 0.5;
+    /// Offsets the noise for the terrain, so water and rock don't line up with the ore patches
+    const TERRAIN_NOISE_OFFSET: f64 = 500.0;
+    /// Offsets the noise for the dunes
+    const DUNE_NOISE_OFFSET: f64 = 1000.0;
+    /// How much the terrain noise is scaled, lakes and rock formations are about 30 tiles across
+    const TERRAIN_SCALE: f64 = 0.03;
+    /// How much the dune noise is scaled, dunes are smaller than lakes
+    const DUNE_SCALE: f64 = 0.08;
+    /// Tiles closer than this to the middle of the map are always sand, so there is room to start building
+    const START_AREA_RADIUS: f64 = 12.0;
+    /// Offsets the noise deciding whether a patch holds one of the resources further out
+    const ADVANCED_RESOURCE_NOISE_OFFSET: f64 = 200.0;
+    /// The resources needed later in the game and how far from the middle of the map they start to appear
+    const ADVANCED_RESOURCES: [(MiddlegroundObject, f64); 3] = [
+        (MiddlegroundObject::Stone, 32.0),
+        (MiddlegroundObject::Quartz, 64.0),
+        (MiddlegroundObject::Oil, 112.0),
+    ];
+
+    pub fn new(seed: Seed, config: WorldGenerationConfig) -> Self {
+        Self {
+            simplex: Simplex::new(seed.0),
+            config,
+        }
+    }
+
+    /// Generate the tile at `tile_pos`
+    pub fn tile(&self, tile_pos: TilePos) -> GeneratedTile {
+        GeneratedTile {
+            background: self.background(tile_pos),
+            ore: self.ore(tile_pos),
+        }
+    }
+
+    /// Generate only the terrain of the tile at `tile_pos`
+    pub fn background(&self, tile_pos: TilePos) -> BackgroundObject {
+        // The start area and the area of a migrated old map are always sand
+        if distance_to_middle(tile_pos) < Self::START_AREA_RADIUS
+            || self.legacy_tile_pos(tile_pos).is_some()
+        {
+            return BackgroundObject::Sand;
+        }
+
+        // The playable area is surrounded by water
+        let min = MAP_SIZE.x.saturating_sub(self.config.map_size) / 2;
+        let max = min + self.config.map_size;
+
+        if tile_pos.x < min || tile_pos.x >= max || tile_pos.y < min || tile_pos.y >= max {
+            return BackgroundObject::Water;
+        }
+
+        // Low terrain is filled with water, high terrain is bare rock
+        let terrain_noise = self.simplex.get([
+            (tile_pos.x as f64 + Self::TERRAIN_NOISE_OFFSET) * Self::TERRAIN_SCALE,
+            (tile_pos.y as f64 + Self::TERRAIN_NOISE_OFFSET) * Self::TERRAIN_SCALE,
+        ]);
+
+        if terrain_noise < -0.55 {
+            return BackgroundObject::Water;
+        }
+
+        if terrain_noise > 0.6 {
+            return BackgroundObject::Rock;
+        }
+
+        let dune_noise = self.simplex.get([
+            (tile_pos.x as f64 + Self::DUNE_NOISE_OFFSET) * Self::DUNE_SCALE,
+            (tile_pos.y as f64 + Self::DUNE_NOISE_OFFSET) * Self::DUNE_SCALE,
+        ]);
+
+        if dune_noise > 0.45 {
+            BackgroundObject::Dune
+        } else {
+            BackgroundObject::Sand
+        }
+    }
+
+    /// The color of the tile at `tile_pos` on maps, its ore if it has any and its terrain otherwise
+    pub fn map_color(&self, tile_pos: TilePos) -> Color {
+        let tile = self.tile(tile_pos);
+
+        tile.ore.map_or_else(
+            || tile.background.map_color(),
+            |ore| ore.resource.map_color(),
+        )
+    }
+
+    /// Generate the ore deposits of the chunk at `chunk_pos`
+    pub fn chunk(&self, chunk_pos: IVec2) -> Chunk {
+        let mut chunk = Chunk::default();
+
+        for tile_pos in chunk_tile_positions(chunk_pos) {
+            if let Some(ore_deposit) = self.tile(tile_pos).ore {
+                chunk.ores.insert(tile_pos, ore_deposit);
+            }
+        }
+
+        chunk
+    }
+
+    fn ore(&self, tile_pos: TilePos) -> Option<OreDeposit> {
+        // The following method isn't exactly the best, but it's enough for this demo.
+        // World Gen isn't the focus of this game
+
+        if let Some(legacy_tile_pos) = self.legacy_tile_pos(tile_pos) {
+            return self.legacy_ore(legacy_tile_pos);
+        }
+
+        // Miners couldn't be built on top of ore in water or rock
+        if !self.background(tile_pos).is_buildable() {
+            return None;
+        }
+
+        // Scale the coordinates to control the patch size
+        let scale = 1.0 / self.config.patch_size;
+        let noise_value = self
+            .simplex
+            .get([tile_pos.x as f64 * scale, tile_pos.y as f64 * scale]);
+
+        // The noise is roughly spread evenly between -1 and 1,
+        // so the threshold is moved down from 1 by twice the wanted frequency
+        let threshold = 1.0 - self.config.ore_frequency.clamp(0.0, 1.0) * 2.0;
+
+        if noise_value <= threshold {
+            return None;
+        }
+
+        // Patches get richer towards their middle
+        let richness = (noise_value - threshold) / (1.0 - threshold);
+        let amount = (Self::MINIMUM_ORE_AMOUNT
+            + richness * (Self::MAXIMUM_ORE_AMOUNT - Self::MINIMUM_ORE_AMOUNT))
+            * self.config.richness;
+
+        // Use another noise layer to determine the resource type
+        let resource_noise = self.simplex.get([
+            (tile_pos.x as f64 + Self::RESOURCE_NOISE_OFFSET) * scale,
+            (tile_pos.y as f64 + Self::RESOURCE_NOISE_OFFSET) * scale,
+        ]);
+
+        // Some patches further out hold the advanced resources instead of the basic ones
+        let advanced_resource_noise = self.simplex.get([
+            (tile_pos.x as f64 + Self::ADVANCED_RESOURCE_NOISE_OFFSET) * scale,
+            (tile_pos.y as f64 + Self::ADVANCED_RESOURCE_NOISE_OFFSET) * scale,
+        ]);
+        let distance = distance_to_middle(tile_pos);
+        let advanced_resources: Vec<MiddlegroundObject> = Self::ADVANCED_RESOURCES
+            .iter()
+            .filter(|&&(_, min_distance)| distance >= min_distance)
+            .map(|&(resource, _)| resource)
+            .collect();
+
+        let resource = if advanced_resource_noise > 0.2 && !advanced_resources.is_empty() {
+            // Spread the resource noise evenly over the available resources
+            let index = ((resource_noise + 1.0) / 2.0 * advanced_resources.len() as f64) as usize;
+            advanced_resources[index.min(advanced_resources.len() - 1)]
+        } else if resource_noise < -0.3 {
+            MiddlegroundObject::Iron
+        } else if resource_noise < 0.3 {
+            MiddlegroundObject::Copper
+        } else {
+            MiddlegroundObject::Coal
+        };
+
+        Some(OreDeposit {
+            resource,
+            amount: (amount as u32).max(1),
+        })
+    }
+
+    /// The position `tile_pos` had in the old world, `None` if it's outside of the legacy area
+    fn legacy_tile_pos(&self, tile_pos: TilePos) -> Option<TilePos> {
+        let min = MAP_SIZE.x.saturating_sub(self.config.legacy_area_size) / 2;
+        let max = min + self.config.legacy_area_size;
+
+        (tile_pos.x >= min && tile_pos.x < max && tile_pos.y >= min && tile_pos.y < max).then(
+            || TilePos {
+                x: tile_pos.x - min,
+                y: tile_pos.y - min,
+            },
+        )
+    }
+
+    /// The ore the world generation from before the map was split into chunks put at `legacy_tile_pos`
+    fn legacy_ore(&self, legacy_tile_pos: TilePos) -> Option<OreDeposit> {
+        let scale = Self::LEGACY_PATCH_SCALE;
+        let noise_value = self.simplex.get([
+            legacy_tile_pos.x as f64 * scale,
+            legacy_tile_pos.y as f64 * scale,
+        ]);
+
+        if noise_value <= Self::LEGACY_ORE_THRESHOLD {
+            return None;
+        }
+
+        let richness =
+            (noise_value - Self::LEGACY_ORE_THRESHOLD) / (1.0 - Self::LEGACY_ORE_THRESHOLD);
+        let amount = Self::MINIMUM_ORE_AMOUNT
+            + richness * (Self::MAXIMUM_ORE_AMOUNT - Self::MINIMUM_ORE_AMOUNT);
+
+        let resource_noise = self.simplex.get([
+            (legacy_tile_pos.x as f64 + Self::RESOURCE_NOISE_OFFSET) * scale,
+            (legacy_tile_pos.y as f64 + Self::RESOURCE_NOISE_OFFSET) * scale,
+        ]);
+
+        let resource = if resource_noise < -0.3 {
+            MiddlegroundObject::Iron
+        } else if resource_noise < 0.3 {
+            MiddlegroundObject::Copper
+        } else {
+            MiddlegroundObject::Coal
+        };
+
+        Some(OreDeposit {
+            resource,
+            amount: amount as u32,
+        })
+    }
+}
+
+/// Create the empty background and middleground tilemaps and restore the generated chunks
+///
+/// The tiles themselves are spawned chunk by chunk around the camera.
+pub fn generation(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    game_save: Res<LoadedGameSave>,
+    seed: Res<Seed>,
+    new_world_settings: Option<Res<NewWorldSettings>>,
+) {
     let background_texture_handle = asset_server.load("background_tiles.png");
     let middleground_texture_handle = asset_server.load("middleground_tiles.png");
 
-    // Create the background tilemap full of sand
-    generate_tilemap_layer(
-        &mut commands,
-        background_texture_handle,
-        RenderLayer::Background.into(),
-        TilemapSettings::new(MAP_SIZE, TILE_SIZE, MAP_TYPE, TILE_SIZE.into()),
+    commands.spawn((
+        make_tilemap_bundle(
+            TilemapAnchor::Center,
+            background_texture_handle,
+            TileStorage::empty(MAP_SIZE),
+            RenderLayer::Background.into(),
+            TilemapSettings::new(MAP_SIZE, TILE_SIZE, MAP_TYPE, TILE_SIZE.into()),
+        ),
         Background,
-        |_| Some((BackgroundObject::Sand.into(), Background)),
-    );
-
-    // Generate the middlground tilemap for resources
-    generate_tilemap_layer(
-        &mut commands,
-        middleground_texture_handle,
-        RenderLayer::Middleground.into(),
-        TilemapSettings::new(MAP_SIZE, TILE_SIZE, MAP_TYPE, TILE_SIZE.into()),
+    ));
+
+    commands.spawn((
+        make_tilemap_bundle(
+            TilemapAnchor::Center,
+            middleground_texture_handle,
+            TileStorage::empty(MAP_SIZE),
+            RenderLayer::Middleground.into(),
+            TilemapSettings::new(MAP_SIZE, TILE_SIZE, MAP_TYPE, TILE_SIZE.into()),
+        ),
         Middleground,
-        |tile_pos| {
-            // The following method isn't exactly the best, but it's enough for this demo.
-            // World Gen isn't the focus of this game
-
-            let simplex = Simplex::new(seed.0);
-
-            // Scale the coordinates to control patch frequency
-            let scale = 0.1; // Lower values = larger patches
-            let noise_value = simplex.get([tile_pos.x as f64 * scale, tile_pos.y as f64 * scale]);
-
-            // Threshold to determine if the tile has a resource
-            if noise_value > 0.5 {
-                // Use another noise layer to determine the resource type
-                let resource_noise = simplex.get([
-                    (tile_pos.x as f64 + 100.0) * scale,
-                    (tile_pos.y as f64 + 100.0) * scale,
-                ]);
-
-                let middleground_object = if resource_noise < -0.3 {
-                    MiddlegroundObject::Iron
-                } else if resource_noise < 0.3 {
-                    MiddlegroundObject::Copper
-                } else {
-                    MiddlegroundObject::Coal
-                };
+    ));
 
-                Some((middleground_object.into(), Middleground))
-            } else {
-                None // No resource in this tile
-            }
-        },
-    );
+    // Start with the chunks and config of the save, if there is one
+    let (world_chunks, world_generation_config) = match &**game_save {
+        Some(game_save) => (
+            game_save.world_chunks.clone(),
+            game_save.world_generation_config,
+        ),
+        None => (
+            WorldChunks::default(),
+            new_world_settings.map_or_else(default, |settings| settings.config),
+        ),
+    };
+
+    commands.insert_resource(world_chunks);
+    commands.insert_resource(world_generation_config);
+    commands.insert_resource(WorldGenerator::new(*seed, world_generation_config));
+    commands.init_resource::<LoadedChunks>();
+}
+
+/// How many tiles `tile_pos` is away from the middle of the map, where the game starts
+fn distance_to_middle(tile_pos: TilePos) -> f64 {
+    let x = tile_pos.x as f64 - MAP_SIZE.x as f64 / 2.0;
+    let y = tile_pos.y as f64 - MAP_SIZE.y as f64 / 2.0;
+
+    (x * x + y * y).sqrt()
+}
+
+/// All tile positions in the chunk at `chunk_pos`
+pub fn chunk_tile_positions(chunk_pos: IVec2) -> impl Iterator<Item = TilePos> {
+    let origin = chunk_pos.as_uvec2() * CHUNK_SIZE;
+
+    (0..CHUNK_SIZE).flat_map(move |x| {
+        (0..CHUNK_SIZE).map(move |y| TilePos {
+            x: origin.x + x,
+            y: origin.y + y,
+        })
+    })
 }
 
 pub fn cleanup(
@@ -75,3 +423,222 @@ pub fn cleanup(
     commands.entity(background_tilemap.entity()).despawn();
     commands.entity(middleground_tilemap.entity()).despawn();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::plugins::world::ChunkOres;
+
+    const SEED: Seed = Seed(1234);
+
+    /// The chunks of a square area of the world, in the order they are generated
+    fn chunk_positions() -> Vec<IVec2> {
+        (0..4)
+            .flat_map(|x| (0..4).map(move |y| IVec2::new(x, y)))
+            .collect()
+    }
+
+    fn count_ore_tiles(config: WorldGenerationConfig) -> usize {
+        let world_generator = WorldGenerator::new(SEED, config);
+
+        chunk_positions()
+            .into_iter()
+            .map(|chunk_pos| world_generator.chunk(chunk_pos).ores.len())
+            .sum()
+    }
+
+    #[test]
+    fn same_seed_generates_same_tiles() {
+        let first = WorldGenerator::new(SEED, WorldGenerationConfig::default());
+        let second = WorldGenerator::new(SEED, WorldGenerationConfig::default());
+
+        for x in 0..64 {
+            for y in 0..64 {
+                let tile_pos = TilePos { x, y };
+                assert_eq!(first.tile(tile_pos), second.tile(tile_pos));
+            }
+        }
+    }
+
+    #[test]
+    fn chunks_match_their_tiles() {
+        let world_generator = WorldGenerator::new(SEED, WorldGenerationConfig::default());
+        let chunk_pos = IVec2::new(3, 5);
+        let chunk = world_generator.chunk(chunk_pos);
+
+        for tile_pos in chunk_tile_positions(chunk_pos) {
+            assert_eq!(
+                chunk.ores.get(&tile_pos).copied(),
+                world_generator.tile(tile_pos).ore
+            );
+        }
+    }
+
+    #[test]
+    fn generation_order_does_not_matter() {
+        let world_generator = WorldGenerator::new(SEED, WorldGenerationConfig::default());
+
+        let forwards: Vec<ChunkOres> = chunk_positions()
+            .into_iter()
+            .map(|chunk_pos| world_generator.chunk(chunk_pos).ores)
+            .collect();
+        let mut backwards: Vec<ChunkOres> = chunk_positions()
+            .into_iter()
+            .rev()
+            .map(|chunk_pos| world_generator.chunk(chunk_pos).ores)
+            .collect();
+        backwards.reverse();
+
+        assert_eq!(forwards, backwards);
+    }
+
+    #[test]
+    fn different_seeds_generate_different_worlds() {
+        let first = WorldGenerator::new(Seed(1), WorldGenerationConfig::default());
+        let second = WorldGenerator::new(Seed(2), WorldGenerationConfig::default());
+
+        assert!(
+            chunk_positions()
+                .into_iter()
+                .any(|chunk_pos| first.chunk(chunk_pos).ores != second.chunk(chunk_pos).ores)
+        );
+    }
+
+    #[test]
+    fn ore_frequency_controls_ore_amount() {
+        let none = count_ore_tiles(WorldGenerationConfig {
+            ore_frequency: 0.0,
+            ..default()
+        });
+        let normal = count_ore_tiles(WorldGenerationConfig::default());
+        let more = count_ore_tiles(WorldGenerationConfig {
+            ore_frequency: 0.5,
+            ..default()
+        });
+
+        assert_eq!(none, 0);
+        assert!(normal < more);
+    }
+
+    #[test]
+    fn richness_scales_ore_deposits() {
+        let normal = WorldGenerator::new(SEED, WorldGenerationConfig::default());
+        let rich = WorldGenerator::new(
+            SEED,
+            WorldGenerationConfig {
+                richness: 2.0,
+                ..default()
+            },
+        );
+
+        for tile_pos in chunk_tile_positions(IVec2::ZERO) {
+            match (normal.tile(tile_pos).ore, rich.tile(tile_pos).ore) {
+                (Some(normal_ore), Some(rich_ore)) => {
+                    assert_eq!(normal_ore.resource, rich_ore.resource);
+                    assert!(rich_ore.amount > normal_ore.amount);
+                }
+                (None, None) => {}
+                _ => panic!("Richness shouldn't change where ore is"),
+            }
+        }
+    }
+
+    #[test]
+    fn legacy_area_keeps_the_old_world() {
+        let legacy_area_size = 64;
+        let world_generator = WorldGenerator::new(
+            SEED,
+            WorldGenerationConfig {
+                legacy_area_size,
+                ..default()
+            },
+        );
+        let offset = (MAP_SIZE.x - legacy_area_size) / 2;
+
+        for x in 0..legacy_area_size {
+            for y in 0..legacy_area_size {
+                let tile = world_generator.tile(TilePos {
+                    x: x + offset,
+                    y: y + offset,
+                });
+
+                assert_eq!(tile.background, BackgroundObject::Sand);
+                assert_eq!(tile.ore, world_generator.legacy_ore(TilePos { x, y }));
+            }
+        }
+    }
+
+    #[test]
+    fn ore_is_only_on_buildable_terrain() {
+        let world_generator = WorldGenerator::new(SEED, WorldGenerationConfig::default());
+
+        for chunk_pos in chunk_positions() {
+            for tile_pos in chunk_tile_positions(chunk_pos) {
+                let tile = world_generator.tile(tile_pos);
+
+                if tile.ore.is_some() {
+                    assert!(tile.background.is_buildable());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn advanced_resources_keep_their_distance() {
+        let world_generator = WorldGenerator::new(SEED, WorldGenerationConfig::default());
+
+        // A strip from the middle of the map to its edge
+        for x in MAP_SIZE.x / 2..MAP_SIZE.x {
+            for y in MAP_SIZE.y / 2..MAP_SIZE.y / 2 + 32 {
+                let tile_pos = TilePos { x, y };
+
+                let Some(ore) = world_generator.tile(tile_pos).ore else {
+                    continue;
+                };
+
+                if let Some(&(_, min_distance)) = WorldGenerator::ADVANCED_RESOURCES
+                    .iter()
+                    .find(|(resource, _)| *resource == ore.resource)
+                {
+                    assert!(distance_to_middle(tile_pos) >= min_distance);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn small_maps_are_surrounded_by_water() {
+        let world_generator = WorldGenerator::new(
+            SEED,
+            WorldGenerationConfig::from_presets(MapSizePreset::Small, ResourcePreset::Normal),
+        );
+        let border = (MAP_SIZE.x - MapSizePreset::Small.size()) / 2;
+
+        for x in 0..MAP_SIZE.x {
+            for y in [0, border - 1, MAP_SIZE.y - border, MAP_SIZE.y - 1] {
+                let tile_pos = TilePos { x, y };
+
+                assert_eq!(
+                    world_generator.background(tile_pos),
+                    BackgroundObject::Water
+                );
+                assert_eq!(world_generator.tile(tile_pos).ore, None);
+            }
+        }
+    }
+
+    #[test]
+    fn start_area_is_buildable() {
+        let world_generator = WorldGenerator::new(SEED, WorldGenerationConfig::default());
+        let middle = TilePos {
+            x: MAP_SIZE.x / 2,
+            y: MAP_SIZE.y / 2,
+        };
+
+        for x in middle.x - 8..middle.x + 8 {
+            for y in middle.y - 8..middle.y + 8 {
+                assert!(world_generator.background(TilePos { x, y }).is_buildable());
+            }
+        }
+    }
+}
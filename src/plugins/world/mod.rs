@@ -1,39 +1,86 @@
+use std::collections::{HashMap, HashSet};
+
 use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
-use generation::{cleanup, generation};
+use chunks::update_loaded_chunks;
+use depletion::deplete_world_chunks;
+use generation::{WorldGenerationConfig, WorldGenerator, cleanup, generation};
 
 use rand::Rng;
 use serde::{Deserialize, Serialize};
+use strum_macros::Display;
 
-use crate::game_save_types::LoadedGameSave;
+use crate::{
+    content::{items::ItemType, machine_types::Machine},
+    game_save_types::LoadedGameSave,
+};
 
-use super::menu::GameState;
+use super::{
+    building::foreground_objects::ForegroundObject,
+    menu::GameState,
+    simulation::{SimulationSet, SimulationUpdate},
+};
 
-mod generation;
+pub mod chunks;
+mod depletion;
+pub mod generation;
 
 // MARK: Constants
 // for world generation
-pub const MAP_SIZE: TilemapSize = TilemapSize { x: 64, y: 64 };
+pub const MAP_SIZE: TilemapSize = TilemapSize { x: 512, y: 512 };
 pub const TILE_SIZE: TilemapTileSize = TilemapTileSize { x: 8.0, y: 8.0 };
 pub const MAP_TYPE: TilemapType = TilemapType::Square;
+/// The width and height of a chunk in tiles
+pub const CHUNK_SIZE: u32 = 16;
 
 // MARK: Plugin
 pub struct WorldPlugin;
 
 impl Plugin for WorldPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(OnEnter(GameState::Game), (startup, generation).chain())
+        app.add_event::<ChunkEvent>()
+            .add_systems(OnEnter(GameState::Game), (startup, generation).chain())
+            .add_systems(
+                Update,
+                update_loaded_chunks.run_if(in_state(GameState::Game)),
+            )
+            .add_systems(
+                SimulationUpdate,
+                deplete_world_chunks.in_set(SimulationSet::PostSimulate),
+            )
             .add_systems(OnExit(GameState::Game), (cleanup_bevy_resources, cleanup));
     }
 }
 
 #[allow(unused)]
-#[derive(Default, Clone, Copy)]
-enum BackgroundObject {
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BackgroundObject {
     Sand,
     Water,
     #[default]
     DefaultTile,
+    Rock,
+    Dune,
+}
+
+impl BackgroundObject {
+    /// The color of this terrain on maps
+    pub fn map_color(&self) -> Color {
+        match self {
+            BackgroundObject::Sand => Color::srgb_u8(0xe1, 0xaa, 0x72),
+            BackgroundObject::Water => Color::srgb_u8(0x00, 0x20, 0xb9),
+            BackgroundObject::DefaultTile => Color::srgb_u8(0x80, 0x80, 0x80),
+            BackgroundObject::Rock => Color::srgb_u8(0x8a, 0x6f, 0x55),
+            BackgroundObject::Dune => Color::srgb_u8(0xd2, 0x9a, 0x5f),
+        }
+    }
+
+    /// Whether buildings can be placed on this terrain
+    ///
+    /// Belts have to go around water and rock, or under it with tunnels.
+    pub fn is_buildable(&self) -> bool {
+        !matches!(self, BackgroundObject::Water | BackgroundObject::Rock)
+    }
 }
 
 impl From<BackgroundObject> for TileTextureIndex {
@@ -42,6 +89,8 @@ impl From<BackgroundObject> for TileTextureIndex {
             BackgroundObject::Sand => 0,
             BackgroundObject::Water => 1,
             BackgroundObject::DefaultTile => 2,
+            BackgroundObject::Rock => 3,
+            BackgroundObject::Dune => 4,
         };
 
         TileTextureIndex(index)
@@ -50,11 +99,46 @@ impl From<BackgroundObject> for TileTextureIndex {
 
 // MARK: Other
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display, Serialize, Deserialize)]
 pub enum MiddlegroundObject {
+    #[strum(to_string = "Coal Ore")]
     Coal,
+    #[strum(to_string = "Copper Ore")]
     Copper,
+    #[strum(to_string = "Iron Ore")]
     Iron,
+    #[strum(to_string = "Quartz Sand")]
+    Quartz,
+    #[strum(to_string = "Stone")]
+    Stone,
+    #[strum(to_string = "Oil Seep")]
+    Oil,
+}
+
+impl MiddlegroundObject {
+    /// The color of this resource on maps
+    pub fn map_color(&self) -> Color {
+        match self {
+            MiddlegroundObject::Coal => Color::srgb_u8(0x2a, 0x2a, 0x2a),
+            MiddlegroundObject::Copper => Color::srgb_u8(0xc6, 0x7c, 0x11),
+            MiddlegroundObject::Iron => Color::srgb_u8(0xae, 0xb3, 0xb9),
+            MiddlegroundObject::Quartz => Color::srgb_u8(0xf1, 0xe3, 0xc2),
+            MiddlegroundObject::Stone => Color::srgb_u8(0x6e, 0x67, 0x5d),
+            MiddlegroundObject::Oil => Color::srgb_u8(0x3a, 0x33, 0x46),
+        }
+    }
+
+    /// The item a miner gets out of this resource
+    pub fn mined_item(&self) -> ItemType {
+        match self {
+            MiddlegroundObject::Coal => ItemType::Coal,
+            MiddlegroundObject::Copper => ItemType::RawCopper,
+            MiddlegroundObject::Iron => ItemType::RawIron,
+            MiddlegroundObject::Quartz => ItemType::QuartzSand,
+            MiddlegroundObject::Stone => ItemType::Stone,
+            MiddlegroundObject::Oil => ItemType::CrudeOil,
+        }
+    }
 }
 
 impl From<MiddlegroundObject> for TileTextureIndex {
@@ -63,6 +147,9 @@ impl From<MiddlegroundObject> for TileTextureIndex {
             MiddlegroundObject::Coal => 0,
             MiddlegroundObject::Copper => 1,
             MiddlegroundObject::Iron => 2,
+            MiddlegroundObject::Quartz => 3,
+            MiddlegroundObject::Stone => 4,
+            MiddlegroundObject::Oil => 5,
         };
 
         TileTextureIndex(index)
@@ -77,6 +164,9 @@ impl TryFrom<TileTextureIndex> for MiddlegroundObject {
             0 => Ok(MiddlegroundObject::Coal),
             1 => Ok(MiddlegroundObject::Copper),
             2 => Ok(MiddlegroundObject::Iron),
+            3 => Ok(MiddlegroundObject::Quartz),
+            4 => Ok(MiddlegroundObject::Stone),
+            5 => Ok(MiddlegroundObject::Oil),
             _ => Err(()),
         }
     }
@@ -84,14 +174,139 @@ impl TryFrom<TileTextureIndex> for MiddlegroundObject {
 
 // MARK: Resources
 
-#[derive(Resource, Clone, Copy, Serialize, Deserialize)]
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Seed(u32);
 
 impl Seed {
+    pub fn new(value: u32) -> Self {
+        Self(value)
+    }
+
     /// Generates a new random seed
-    fn random() -> Self {
+    pub fn random() -> Self {
         Self(rand::rng().random())
     }
+
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+}
+
+/// The seed and config chosen on the new game screen
+///
+/// Only used if no save is loaded, otherwise the world of the save is restored.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct NewWorldSettings {
+    pub seed: Seed,
+    pub config: WorldGenerationConfig,
+}
+
+/// Every chunk of the world that was generated so far, keyed by its position in chunks
+///
+/// Chunks stay in here after they are unloaded, so mined out ore isn't regenerated and their machines are kept.
+/// It's saved as a list, because not every save format supports maps with non-string keys.
+#[derive(Resource, Debug, Clone, Default, Serialize, Deserialize, Deref, DerefMut)]
+#[serde(from = "Vec<(IVec2, Chunk)>", into = "Vec<(IVec2, Chunk)>")]
+pub struct WorldChunks(HashMap<IVec2, Chunk>);
+
+impl WorldChunks {
+    /// The ore deposit at `tile_pos`, `None` if its chunk wasn't generated yet or there is no ore
+    pub fn ore_at(&self, tile_pos: &TilePos) -> Option<&OreDeposit> {
+        self.get(&chunk_pos(tile_pos))?.ores.get(tile_pos)
+    }
+
+    /// Mutable access to the ore deposit at `tile_pos`, see [WorldChunks::ore_at]
+    pub fn ore_at_mut(&mut self, tile_pos: &TilePos) -> Option<&mut OreDeposit> {
+        self.get_mut(&chunk_pos(tile_pos))?.ores.get_mut(tile_pos)
+    }
+}
+
+impl From<Vec<(IVec2, Chunk)>> for WorldChunks {
+    fn from(value: Vec<(IVec2, Chunk)>) -> Self {
+        Self(value.into_iter().collect())
+    }
+}
+
+impl From<WorldChunks> for Vec<(IVec2, Chunk)> {
+    fn from(value: WorldChunks) -> Self {
+        value.0.into_iter().collect()
+    }
+}
+
+/// The state of a generated part of the world
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Chunk {
+    pub ores: ChunkOres,
+    /// The machines whose south west tile is in this chunk
+    ///
+    /// Only filled while the chunk is unloaded and in saves, the machines of loaded chunks are in the world.
+    #[serde(default)]
+    pub machines: Vec<ChunkMachine>,
+}
+
+/// A machine kept in its chunk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChunkMachine {
+    pub tile_pos: TilePos,
+    pub foreground_object: ForegroundObject,
+    pub machine: Machine,
+}
+
+/// The ore of every resource tile in a chunk, mined out tiles stay in here with an amount of zero
+///
+/// Saved as a list for the same reason as [WorldChunks].
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Deref, DerefMut)]
+#[serde(
+    from = "Vec<(TilePos, OreDeposit)>",
+    into = "Vec<(TilePos, OreDeposit)>"
+)]
+pub struct ChunkOres(HashMap<TilePos, OreDeposit>);
+
+impl From<Vec<(TilePos, OreDeposit)>> for ChunkOres {
+    fn from(value: Vec<(TilePos, OreDeposit)>) -> Self {
+        Self(value.into_iter().collect())
+    }
+}
+
+impl From<ChunkOres> for Vec<(TilePos, OreDeposit)> {
+    fn from(value: ChunkOres) -> Self {
+        value.0.into_iter().collect()
+    }
+}
+
+/// A resource tile and the amount of ore left in it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OreDeposit {
+    pub resource: MiddlegroundObject,
+    pub amount: u32,
+}
+
+/// The chunks whose tiles and machines are currently spawned around the camera
+#[derive(Resource, Default, Deref, DerefMut)]
+pub struct LoadedChunks(HashSet<IVec2>);
+
+impl LoadedChunks {
+    /// Whether buildings can be placed on `tile_pos`
+    ///
+    /// Its chunk has to be loaded. So do the chunks south and west of it,
+    /// because the bigger machines kept in them can reach into it.
+    pub fn can_build_on(&self, tile_pos: &TilePos) -> bool {
+        let chunk_pos = chunk_pos(tile_pos);
+
+        [IVec2::ZERO, IVec2::X, IVec2::Y, IVec2::ONE]
+            .into_iter()
+            .map(|offset| chunk_pos - offset)
+            .all(|chunk_pos| chunk_pos.min_element() < 0 || self.contains(&chunk_pos))
+    }
+}
+
+// MARK: Events
+
+/// Sent when the tiles of a chunk were spawned or despawned around the camera
+#[derive(Event, Debug, Clone, Copy)]
+pub enum ChunkEvent {
+    Loaded(IVec2),
+    Unloaded(IVec2),
 }
 
 // MARK: Components
@@ -104,13 +319,42 @@ pub struct Middleground;
 
 // MARK: Systems
 
-fn startup(mut commands: Commands, game_save: Res<LoadedGameSave>) {
-    match &**game_save {
-        Some(game_save) => commands.insert_resource(game_save.seed),
-        None => commands.insert_resource(Seed::random()),
+fn startup(
+    mut commands: Commands,
+    game_save: Res<LoadedGameSave>,
+    new_world_settings: Option<Res<NewWorldSettings>>,
+) {
+    match (&**game_save, new_world_settings) {
+        (Some(game_save), _) => commands.insert_resource(game_save.seed),
+        (None, Some(new_world_settings)) => commands.insert_resource(new_world_settings.seed),
+        (None, None) => commands.insert_resource(Seed::random()),
     }
 }
 
 fn cleanup_bevy_resources(mut commands: Commands) {
     commands.remove_resource::<Seed>();
+    commands.remove_resource::<WorldChunks>();
+    commands.remove_resource::<WorldGenerationConfig>();
+    commands.remove_resource::<WorldGenerator>();
+    commands.remove_resource::<NewWorldSettings>();
+    commands.remove_resource::<LoadedChunks>();
+}
+
+/// The position of the chunk `tile_pos` is in
+pub fn chunk_pos(tile_pos: &TilePos) -> IVec2 {
+    IVec2::new(
+        (tile_pos.x / CHUNK_SIZE) as i32,
+        (tile_pos.y / CHUNK_SIZE) as i32,
+    )
+}
+
+/// The center of the tile at `tile_pos` in world coordinates
+pub fn tile_center(tile_pos: TilePos) -> Vec2 {
+    tile_pos.center_in_world(
+        &MAP_SIZE,
+        &TILE_SIZE.into(),
+        &TILE_SIZE,
+        &MAP_TYPE,
+        &TilemapAnchor::Center,
+    )
 }
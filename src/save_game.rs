@@ -2,12 +2,17 @@ use bevy::prelude::*;
 use bevy_ecs_tilemap::prelude::*;
 
 use bevy_pkv::PkvStore;
-use dyn_clone::clone_box;
 
 use crate::{
     content::machine_types::Machine,
-    game_save_types::{GameSave, MachineTiles},
-    plugins::{building::foreground_objects::ForegroundObject, world::Seed},
+    game_save_types::GameSave,
+    plugins::{
+        building::foreground_objects::ForegroundObject,
+        camera::CameraBookmarks,
+        completion::GameStatistics,
+        points::SinkStatistics,
+        world::{ChunkMachine, Seed, WorldChunks, chunk_pos, generation::WorldGenerationConfig},
+    },
     save_keys::SaveKey,
 };
 
@@ -17,23 +22,40 @@ pub fn save_game(
     seed: &Seed,
     machine_tiles: Vec<(&TilePos, &TileTextureIndex, &Machine)>,
     camera_translation: Vec3,
+    camera_scale: f32,
+    camera_bookmarks: &CameraBookmarks,
     has_completed_game: bool,
+    sink_statistics: &SinkStatistics,
+    world_chunks: &WorldChunks,
+    world_generation_config: &WorldGenerationConfig,
+    game_statistics: &GameStatistics,
 ) {
-    let mut saved_tiles: MachineTiles = Vec::new();
+    // The machines of the loaded chunks are in the world, all the others are already in their chunks
+    let mut world_chunks = world_chunks.clone();
 
     for (tile_pos, tile_texture_index, machine) in machine_tiles {
-        let foreground_object = ForegroundObject::from(*tile_texture_index);
-
-        saved_tiles.push((
-            *tile_pos,
-            foreground_object,
-            clone_box(&*machine.machine_type),
-            machine.input_items.clone(),
-            machine.output_items.clone(),
-        ));
+        world_chunks
+            .get_mut(&chunk_pos(tile_pos))
+            .expect("Machines should only be in generated chunks")
+            .machines
+            .push(ChunkMachine {
+                tile_pos: *tile_pos,
+                foreground_object: ForegroundObject::from(*tile_texture_index),
+                machine: machine.clone(),
+            });
     }
 
-    let game_save = GameSave::new(saved_tiles, *seed, camera_translation, has_completed_game);
+    let game_save = GameSave::new(
+        *seed,
+        camera_translation,
+        camera_scale,
+        camera_bookmarks.clone(),
+        has_completed_game,
+        sink_statistics.clone(),
+        world_chunks,
+        *world_generation_config,
+        game_statistics.clone(),
+    );
 
     pkv.set(SaveKey::GameSave, &game_save)
         .expect("An error occured while trying to save the game");